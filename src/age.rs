@@ -0,0 +1,91 @@
+//! Ages by the traditional reckoning (虚岁) and the Western one, and the returns of the animal of
+//! the birth year (本命年).
+
+use chrono::Datelike;
+
+use crate::shengxiao::YearBoundary;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Age {
+    /// The traditional age, which is 1 at birth and goes up at the beginning of each year.
+    pub xusui: u32,
+    /// Completed years, which go up on each Gregorian birthday.
+    pub western: u32,
+    /// Completed cycles of the 12 animals since the year of birth.
+    pub zodiac_cycles: u32,
+}
+
+impl Age {
+    /// The age on a date of a person born on another, the years beginning at the boundary.
+    pub fn new(
+        birth: &impl Datelike, date: &impl Datelike, boundary: YearBoundary,
+    ) -> Option<Self> {
+        let birth_year = boundary.year_of(birth)?.get();
+        let year = boundary.year_of(date)?.get();
+        let western = date.year()
+            - birth.year()
+            - ((date.month(), date.day()) < (birth.month(), birth.day())) as i32;
+        // A date before the birth has no age
+        let western = u32::try_from(western).ok()?;
+        let years = (year - birth_year) as u32;
+        Some(Self {
+            xusui: years + 1,
+            western,
+            zodiac_cycles: years / 12,
+        })
+    }
+    /// Whether the year is a 本命年, in which the animal of the birth year returns.
+    #[allow(clippy::manual_is_multiple_of)] // is_multiple_of needs Rust 1.87
+    pub fn is_benmingnian(self) -> bool {
+        self.xusui > 1 && (self.xusui - 1) % 12 == 0
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    use crate::date;
+
+    // Born before both Chunjie and Lichun of 1990, in the year of the Snake
+    let birth = date(1990, 1, 20);
+    let age = Age::new(&birth, &date(2024, 1, 20), YearBoundary::Chunjie).unwrap();
+    assert_eq!(
+        age,
+        Age {
+            xusui: 35,
+            western: 34,
+            zodiac_cycles: 2,
+        }
+    );
+    assert!(!age.is_benmingnian());
+    // Lichun of 2024 is on 02-04, and Chunjie on 02-10
+    let age = Age::new(&birth, &date(2024, 2, 5), YearBoundary::Chunjie).unwrap();
+    assert_eq!(age.xusui, 35);
+    let age = Age::new(&birth, &date(2024, 2, 5), YearBoundary::Lichun).unwrap();
+    assert_eq!(age.xusui, 36);
+    assert_eq!(age.western, 34);
+    assert_eq!(
+        Age::new(&birth, &date(1990, 1, 19), YearBoundary::Chunjie),
+        None
+    );
+    let age = Age::new(&birth, &birth, YearBoundary::Chunjie).unwrap();
+    assert_eq!((age.xusui, age.western), (1, 0));
+    assert!(!age.is_benmingnian());
+
+    // Born in the year of the Dragon of 2000, which returns in 2024
+    let age = Age::new(&date(2000, 6, 1), &date(2024, 5, 31), YearBoundary::Chunjie).unwrap();
+    assert_eq!(age.western, 23);
+    assert_eq!(age.xusui, 25);
+    assert_eq!(age.zodiac_cycles, 2);
+    assert!(age.is_benmingnian());
+    // Born on 02-29, a year older on 03-01 in common years
+    let birth = date(2004, 2, 29);
+    assert_eq!(
+        Age::new(&birth, &date(2005, 2, 28), YearBoundary::Chunjie).map(|age| age.western),
+        Some(0)
+    );
+    assert_eq!(
+        Age::new(&birth, &date(2005, 3, 1), YearBoundary::Chunjie).map(|age| age.western),
+        Some(1)
+    );
+}
@@ -0,0 +1,723 @@
+//! Astronomical algorithms for the sun and the moon, mostly from Jean Meeus' *Astronomical
+//! Algorithms*. Times are Julian days in Universal Time unless stated otherwise.
+
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+
+/// Julian day of the epoch J2000.0.
+pub const J2000: f64 = 2451545.0;
+/// Mean length of a synodic month, in days.
+pub const SYNODIC_MONTH: f64 = 29.530588861;
+/// Mean length of a tropical year, in days.
+pub const TROPICAL_YEAR: f64 = 365.242189;
+/// Julian day of `1970-01-01T00:00:00Z`.
+pub const UNIX_EPOCH: f64 = 2440587.5;
+
+const UNIX_EPOCH_DAYS_FROM_CE: i64 = 719163;
+
+type Series = &'static [(f64, f64, f64)];
+
+/// Periodic terms of VSOP87 for the heliocentric longitude of the earth, in `1e-8` radians.
+#[allow(clippy::approx_constant)] // Quoted as published
+const EARTH_L: &[Series] = &[
+    &[
+        (175347046.0, 0.0, 0.0),
+        (3341656.0, 4.6692568, 6283.07585),
+        (34894.0, 4.6261, 12566.1517),
+        (3497.0, 2.7441, 5753.3849),
+        (3418.0, 2.8289, 3.5231),
+        (3136.0, 3.6277, 77713.7715),
+        (2676.0, 4.4181, 7860.4194),
+        (2343.0, 6.1352, 3930.2097),
+        (1324.0, 0.7425, 11506.7698),
+        (1273.0, 2.0371, 529.691),
+        (1199.0, 1.1096, 1577.3435),
+        (990.0, 5.233, 5884.927),
+        (902.0, 2.045, 26.298),
+        (857.0, 3.508, 398.149),
+        (780.0, 1.179, 5223.694),
+        (753.0, 2.533, 5507.553),
+        (505.0, 4.583, 18849.228),
+        (492.0, 4.205, 775.523),
+        (357.0, 2.92, 0.067),
+        (317.0, 5.849, 11790.629),
+        (284.0, 1.899, 796.298),
+        (271.0, 0.315, 10977.079),
+        (243.0, 0.345, 5486.778),
+        (206.0, 4.806, 2544.314),
+        (205.0, 1.869, 5573.143),
+        (202.0, 2.458, 6069.777),
+        (156.0, 0.833, 213.299),
+        (132.0, 3.411, 2942.463),
+        (126.0, 1.083, 20.775),
+        (115.0, 0.645, 0.98),
+        (103.0, 0.636, 4694.003),
+        (102.0, 0.976, 15720.839),
+        (102.0, 4.267, 7.114),
+        (99.0, 6.21, 2146.17),
+        (98.0, 0.68, 155.42),
+        (86.0, 5.98, 161000.69),
+        (85.0, 1.3, 6275.96),
+        (85.0, 3.67, 71430.7),
+        (80.0, 1.81, 17260.15),
+        (79.0, 3.04, 12036.46),
+        (75.0, 1.76, 5088.63),
+        (74.0, 3.5, 3154.69),
+        (74.0, 4.68, 801.82),
+        (70.0, 0.83, 9437.76),
+        (62.0, 3.98, 8827.39),
+        (61.0, 1.82, 7084.9),
+        (57.0, 2.78, 6286.6),
+        (56.0, 4.39, 14143.5),
+        (56.0, 3.47, 6279.55),
+        (52.0, 0.19, 12139.55),
+        (52.0, 1.33, 1748.02),
+        (51.0, 0.28, 5856.48),
+        (49.0, 0.49, 1194.45),
+        (41.0, 5.37, 8429.24),
+        (41.0, 2.4, 19651.05),
+        (39.0, 6.17, 10447.39),
+        (37.0, 6.04, 10213.29),
+        (37.0, 2.57, 1059.38),
+        (36.0, 1.71, 2352.87),
+        (36.0, 1.78, 6812.77),
+        (33.0, 0.59, 17789.85),
+        (30.0, 0.44, 83996.85),
+        (30.0, 2.74, 1349.87),
+        (25.0, 3.16, 4690.48),
+    ],
+    &[
+        (628331966747.0, 0.0, 0.0),
+        (206059.0, 2.678235, 6283.07585),
+        (4303.0, 2.6351, 12566.1517),
+        (425.0, 1.59, 3.523),
+        (119.0, 5.796, 26.298),
+        (109.0, 2.966, 1577.344),
+        (93.0, 2.59, 18849.23),
+        (72.0, 1.14, 529.69),
+        (68.0, 1.87, 398.15),
+        (67.0, 4.41, 5507.55),
+        (59.0, 2.89, 5223.69),
+        (56.0, 2.17, 155.42),
+        (45.0, 0.4, 796.3),
+        (36.0, 0.47, 775.52),
+        (29.0, 2.65, 7.11),
+        (21.0, 5.34, 0.98),
+        (19.0, 1.85, 5486.78),
+        (19.0, 4.97, 213.3),
+        (17.0, 2.99, 6275.96),
+        (16.0, 0.03, 2544.31),
+        (16.0, 1.43, 2146.17),
+        (15.0, 1.21, 10977.08),
+        (12.0, 2.83, 1748.02),
+        (12.0, 3.26, 5088.63),
+        (12.0, 5.27, 1194.45),
+        (12.0, 2.08, 4694.0),
+        (11.0, 0.77, 553.57),
+        (10.0, 1.3, 6286.6),
+        (10.0, 4.24, 1349.87),
+        (9.0, 2.7, 242.73),
+        (9.0, 5.64, 951.72),
+        (8.0, 5.3, 2352.87),
+        (6.0, 2.65, 9437.76),
+        (6.0, 4.67, 4690.48),
+    ],
+    &[
+        (52919.0, 0.0, 0.0),
+        (8720.0, 1.0721, 6283.0758),
+        (309.0, 0.867, 12566.152),
+        (27.0, 0.05, 3.52),
+        (16.0, 5.19, 26.3),
+        (16.0, 3.68, 155.42),
+        (10.0, 0.76, 18849.23),
+        (9.0, 2.06, 77713.77),
+        (7.0, 0.83, 775.52),
+        (5.0, 4.66, 1577.34),
+        (4.0, 1.03, 7.11),
+        (4.0, 3.44, 5573.14),
+        (3.0, 5.14, 796.3),
+        (3.0, 6.05, 5507.55),
+        (3.0, 1.19, 242.73),
+        (3.0, 6.12, 529.69),
+        (3.0, 0.31, 398.15),
+        (3.0, 2.28, 553.57),
+        (2.0, 4.38, 5223.69),
+        (2.0, 3.75, 0.98),
+    ],
+    &[
+        (289.0, 5.844, 6283.076),
+        (35.0, 0.0, 0.0),
+        (17.0, 5.49, 12566.15),
+        (3.0, 5.2, 155.42),
+        (1.0, 4.72, 3.52),
+        (1.0, 5.3, 18849.23),
+        (1.0, 5.97, 242.73),
+    ],
+    &[(114.0, 3.142, 0.0), (8.0, 4.13, 6283.08), (1.0, 3.84, 12566.15)],
+    &[(1.0, 3.14, 0.0)],
+];
+
+/// Periodic terms of VSOP87 for the radius vector of the earth, in `1e-8` astronomical units.
+#[allow(clippy::approx_constant)] // Quoted as published
+const EARTH_R: &[Series] = &[
+    &[
+        (100013989.0, 0.0, 0.0),
+        (1670700.0, 3.0984635, 6283.07585),
+        (13956.0, 3.05525, 12566.1517),
+        (3084.0, 5.1985, 77713.7715),
+        (1628.0, 1.1739, 5753.3849),
+        (1576.0, 2.8469, 7860.4194),
+        (925.0, 5.453, 11506.77),
+        (542.0, 4.564, 3930.21),
+        (472.0, 3.661, 5884.927),
+        (346.0, 0.964, 5507.553),
+        (329.0, 5.9, 5223.694),
+        (307.0, 0.299, 5573.143),
+        (243.0, 4.273, 11790.629),
+        (212.0, 5.847, 1577.344),
+        (186.0, 5.022, 10977.079),
+        (175.0, 3.012, 18849.228),
+        (110.0, 5.055, 5486.778),
+        (98.0, 0.89, 6069.78),
+        (86.0, 5.69, 15720.84),
+        (86.0, 1.27, 161000.69),
+        (65.0, 0.27, 17260.15),
+        (63.0, 0.92, 529.69),
+        (57.0, 2.01, 83996.85),
+        (56.0, 5.24, 71430.7),
+        (49.0, 3.25, 2544.31),
+        (47.0, 2.58, 775.52),
+        (45.0, 5.54, 9437.76),
+        (43.0, 6.01, 6275.96),
+        (39.0, 5.36, 4694.0),
+        (38.0, 2.39, 8827.39),
+        (37.0, 0.83, 19651.05),
+        (37.0, 4.9, 12139.55),
+        (36.0, 1.67, 12036.46),
+        (35.0, 1.84, 2942.46),
+        (33.0, 0.24, 7084.9),
+        (32.0, 0.18, 5088.63),
+        (32.0, 1.78, 398.15),
+        (28.0, 1.21, 6286.6),
+        (28.0, 1.9, 6279.55),
+        (26.0, 4.59, 10447.39),
+    ],
+    &[
+        (103019.0, 1.10749, 6283.07585),
+        (1721.0, 1.0644, 12566.1517),
+        (702.0, 3.142, 0.0),
+        (32.0, 1.02, 18849.23),
+        (31.0, 2.84, 5507.55),
+        (25.0, 1.32, 5223.69),
+        (18.0, 1.42, 1577.34),
+        (10.0, 5.91, 10977.08),
+        (9.0, 1.42, 6275.96),
+        (9.0, 0.27, 5486.78),
+    ],
+    &[
+        (4359.0, 5.7846, 6283.0758),
+        (124.0, 5.579, 12566.152),
+        (12.0, 3.14, 0.0),
+        (9.0, 3.63, 77713.77),
+        (6.0, 1.87, 5573.14),
+        (3.0, 5.47, 18849.23),
+    ],
+    &[(145.0, 4.273, 6283.076), (7.0, 3.92, 12566.15)],
+    &[(4.0, 2.56, 6283.08)],
+];
+
+/// Principal terms of the IAU 1980 theory of nutation: multiples of D, M, M', F and Ω, and the
+/// coefficients of the nutation in longitude, in `1e-4` arcseconds.
+const NUTATION: &[([i8; 5], f64, f64)] = &[
+    ([0, 0, 0, 0, 1], -171996.0, -174.2),
+    ([-2, 0, 0, 2, 2], -13187.0, -1.6),
+    ([0, 0, 0, 2, 2], -2274.0, -0.2),
+    ([0, 0, 0, 0, 2], 2062.0, 0.2),
+    ([0, 1, 0, 0, 0], 1426.0, -3.4),
+    ([0, 0, 1, 0, 0], 712.0, 0.1),
+    ([-2, 1, 0, 2, 2], -517.0, 1.2),
+    ([0, 0, 0, 2, 1], -386.0, -0.4),
+    ([0, 0, 1, 2, 2], -301.0, 0.0),
+    ([-2, -1, 0, 2, 2], 217.0, -0.5),
+    ([-2, 0, 1, 0, 0], -158.0, 0.0),
+    ([-2, 0, 0, 2, 1], 129.0, 0.1),
+    ([0, 0, -1, 2, 2], 123.0, 0.0),
+    ([2, 0, 0, 0, 0], 63.0, 0.0),
+    ([0, 0, 1, 0, 1], 63.0, 0.1),
+    ([2, 0, -1, 2, 2], -59.0, 0.0),
+    ([0, 0, -1, 0, 1], -58.0, -0.1),
+    ([0, 0, 1, 2, 1], -51.0, 0.0),
+    ([-2, 0, 2, 0, 0], 48.0, 0.0),
+    ([0, 0, -2, 2, 1], 46.0, 0.0),
+    ([2, 0, 0, 2, 2], -38.0, 0.0),
+    ([0, 0, 2, 2, 2], -31.0, 0.0),
+    ([0, 0, 2, 0, 0], 29.0, 0.0),
+    ([-2, 0, 1, 2, 2], 29.0, 0.0),
+    ([0, 0, 0, 2, 0], 26.0, 0.0),
+    ([-2, 0, 0, 2, 0], -22.0, 0.0),
+    ([0, 0, -1, 2, 1], 21.0, 0.0),
+    ([0, 2, 0, 0, 0], 17.0, -0.1),
+    ([2, 0, -1, 0, 1], 16.0, 0.0),
+    ([-2, 2, 0, 2, 2], -16.0, 0.1),
+    ([0, 1, 0, 0, 1], -15.0, 0.0),
+    ([-2, 0, 1, 0, 1], -13.0, 0.0),
+    ([0, -1, 0, 0, 1], -12.0, 0.0),
+    ([0, 0, 2, -2, 0], 11.0, 0.0),
+];
+
+/// Principal periodic terms of ELP-2000/82 for the geocentric longitude of the moon: multiples of
+/// D, M, M' and F, and the coefficient in `1e-6` degrees.
+const MOON_L: &[([i8; 4], f64)] = &[
+    ([0, 0, 1, 0], 6288774.0),
+    ([2, 0, -1, 0], 1274027.0),
+    ([2, 0, 0, 0], 658314.0),
+    ([0, 0, 2, 0], 213618.0),
+    ([0, 1, 0, 0], -185116.0),
+    ([0, 0, 0, 2], -114332.0),
+    ([2, 0, -2, 0], 58793.0),
+    ([2, -1, -1, 0], 57066.0),
+    ([2, 0, 1, 0], 53322.0),
+    ([2, -1, 0, 0], 45758.0),
+    ([0, 1, -1, 0], -40923.0),
+    ([1, 0, 0, 0], -34720.0),
+    ([0, 1, 1, 0], -30383.0),
+    ([2, 0, 0, -2], 15327.0),
+    ([0, 0, 1, 2], -12528.0),
+    ([0, 0, 1, -2], 10980.0),
+    ([4, 0, -1, 0], 10675.0),
+    ([0, 0, 3, 0], 10034.0),
+    ([4, 0, -2, 0], 8548.0),
+    ([2, 1, -1, 0], -7888.0),
+    ([2, 1, 0, 0], -6766.0),
+    ([1, 0, -1, 0], -5163.0),
+    ([1, 1, 0, 0], 4987.0),
+    ([2, -1, 1, 0], 4036.0),
+    ([2, 0, 2, 0], 3994.0),
+    ([4, 0, 0, 0], 3861.0),
+    ([2, 0, -3, 0], 3665.0),
+    ([0, 1, -2, 0], -2689.0),
+    ([2, 0, -1, 2], -2602.0),
+    ([2, -1, -2, 0], 2390.0),
+    ([1, 0, 1, 0], -2348.0),
+    ([2, -2, 0, 0], 2236.0),
+    ([0, 1, 2, 0], -2120.0),
+    ([0, 2, 0, 0], -2069.0),
+    ([2, -2, -1, 0], 2048.0),
+    ([2, 0, 1, -2], -1773.0),
+    ([2, 0, 0, 2], -1595.0),
+    ([4, -1, -1, 0], 1215.0),
+    ([0, 0, 2, 2], -1110.0),
+    ([3, 0, -1, 0], -892.0),
+    ([2, 1, 1, 0], -810.0),
+    ([4, -1, -2, 0], 759.0),
+    ([0, 2, -1, 0], -713.0),
+    ([2, 2, -1, 0], -700.0),
+    ([2, 1, -2, 0], 691.0),
+    ([2, -1, 0, -2], 596.0),
+    ([4, 0, 1, 0], 549.0),
+    ([0, 0, 4, 0], 537.0),
+    ([4, -1, 0, 0], 520.0),
+    ([1, 0, -2, 0], -487.0),
+    ([2, 1, 0, -2], -399.0),
+    ([0, 0, 2, -2], -381.0),
+    ([1, 1, 1, 0], 351.0),
+    ([3, 0, -2, 0], -340.0),
+    ([4, 0, -3, 0], 330.0),
+    ([2, -1, 2, 0], 327.0),
+    ([0, 2, 1, 0], -323.0),
+    ([1, 1, -1, 0], 299.0),
+    ([2, 0, 3, 0], 294.0),
+];
+
+/// Observed ΔT in seconds at the beginning of each year from 2005.
+const OBSERVED_DELTA_T: &[f64] = &[
+    64.69, 64.85, 65.15, 65.46, 65.78, 66.07, 66.32, 66.60, 66.91, 67.28, 67.64, 68.10, 68.59,
+    68.97, 69.22, 69.36, 69.36, 69.29, 69.20, 69.18, 69.14,
+];
+
+fn vsop(series: &[Series], tau: f64) -> f64 {
+    series.iter().rev().fold(0.0, |acc, terms| {
+        acc * tau
+            + terms
+                .iter()
+                .map(|(a, b, c)| a * (b + c * tau).cos())
+                .sum::<f64>()
+    }) * 1e-8
+}
+
+fn polynomial(coefficients: &[f64], x: f64) -> f64 {
+    coefficients.iter().rev().fold(0.0, |acc, c| acc * x + c)
+}
+
+/// ΔT = TT - UT in seconds, by the polynomial expressions of Espenak and Meeus, or by
+/// observations since 2005.
+pub fn delta_t(year: f64) -> f64 {
+    let y = year;
+    let long_term = |y: f64| -20.0 + 32.0 * ((y - 1820.0) / 100.0).powi(2);
+    match y {
+        y if y < -500.0 => long_term(y),
+        y if y < 500.0 => polynomial(
+            &[10583.6, -1014.41, 33.78311, -5.952053, -0.1798452, 0.022174192, 0.0090316521],
+            y / 100.0,
+        ),
+        y if y < 1600.0 => polynomial(
+            &[1574.2, -556.01, 71.23472, 0.319781, -0.8503463, -0.005050998, 0.0083572073],
+            (y - 1000.0) / 100.0,
+        ),
+        y if y < 1700.0 => polynomial(&[120.0, -0.9808, -0.01532, 1.0 / 7129.0], y - 1600.0),
+        y if y < 1800.0 => polynomial(
+            &[8.83, 0.1603, -0.0059285, 0.00013336, -1.0 / 1174000.0],
+            y - 1700.0,
+        ),
+        y if y < 1860.0 => polynomial(
+            &[
+                13.72,
+                -0.332447,
+                0.0068612,
+                0.0041116,
+                -0.00037436,
+                0.0000121272,
+                -0.0000001699,
+                0.000000000875,
+            ],
+            y - 1800.0,
+        ),
+        y if y < 1900.0 => polynomial(
+            &[7.62, 0.5737, -0.251754, 0.01680668, -0.0004473624, 1.0 / 233174.0],
+            y - 1860.0,
+        ),
+        y if y < 1920.0 => polynomial(
+            &[-2.79, 1.494119, -0.0598939, 0.0061966, -0.000197],
+            y - 1900.0,
+        ),
+        y if y < 1941.0 => polynomial(&[21.20, 0.84493, -0.076100, 0.0020936], y - 1920.0),
+        y if y < 1961.0 => polynomial(&[29.07, 0.407, -1.0 / 233.0, 1.0 / 2547.0], y - 1950.0),
+        y if y < 1986.0 => polynomial(&[45.45, 1.067, -1.0 / 260.0, -1.0 / 718.0], y - 1975.0),
+        y if y < 2005.0 => polynomial(
+            &[63.86, 0.3345, -0.060374, 0.0017275, 0.000651814, 0.00002373599],
+            y - 2000.0,
+        ),
+        y if y < 2025.0 => {
+            let index = (y - 2005.0) as usize;
+            let [a, b] = [OBSERVED_DELTA_T[index], OBSERVED_DELTA_T[index + 1]];
+            a + (b - a) * (y - 2005.0).fract()
+        }
+        // Joins the last observation to the long-term parabola
+        y if y < 2050.0 => polynomial(&[69.14, 0.0, 0.0382], y - 2025.0),
+        y if y < 2150.0 => long_term(y) - 0.5628 * (2150.0 - y),
+        y => long_term(y),
+    }
+}
+
+/// Converts a Julian day in Universal Time to a Julian ephemeris day.
+pub fn ut_to_tt(jd: f64) -> f64 {
+    jd + delta_t(2000.0 + (jd - J2000) / 365.25) / 86400.0
+}
+
+/// Converts a Julian ephemeris day to a Julian day in Universal Time.
+pub fn tt_to_ut(jde: f64) -> f64 {
+    jde - delta_t(2000.0 + (jde - J2000) / 365.25) / 86400.0
+}
+
+/// Julian day at 00:00 UT of a date.
+pub fn julian_day(date: NaiveDate) -> f64 {
+    (date.num_days_from_ce() as i64 - UNIX_EPOCH_DAYS_FROM_CE) as f64 + UNIX_EPOCH
+}
+
+/// Julian day of an instant.
+pub fn julian_day_of(datetime: DateTime<Utc>) -> f64 {
+    datetime.timestamp_millis() as f64 / 86400000.0 + UNIX_EPOCH
+}
+
+/// Instant of a Julian day, to the millisecond.
+pub fn datetime(jd: f64) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp_millis(((jd - UNIX_EPOCH) * 86400000.0).round() as i64)
+}
+
+/// Civil date of an instant at a UTC offset in seconds.
+pub fn local_date(jd: f64, offset: i32) -> Option<NaiveDate> {
+    let days = (jd - UNIX_EPOCH + offset as f64 / 86400.0).floor() as i64;
+    NaiveDate::from_num_days_from_ce_opt(i32::try_from(days + UNIX_EPOCH_DAYS_FROM_CE).ok()?)
+}
+
+/// Nutation in longitude in arcseconds, at Julian centuries from J2000.0 in Terrestrial Time.
+pub fn nutation_in_longitude(t: f64) -> f64 {
+    let arguments = [
+        polynomial(&[297.85036, 445267.111480, -0.0019142, 1.0 / 189474.0], t),
+        polynomial(&[357.52772, 35999.050340, -0.0001603, -1.0 / 300000.0], t),
+        polynomial(&[134.96298, 477198.867398, 0.0086972, 1.0 / 56250.0], t),
+        polynomial(&[93.27191, 483202.017538, -0.0036825, 1.0 / 327270.0], t),
+        polynomial(&[125.04452, -1934.136261, 0.0020708, 1.0 / 450000.0], t),
+    ];
+    NUTATION
+        .iter()
+        .map(|(multiples, a, b)| {
+            let argument: f64 = multiples
+                .iter()
+                .zip(arguments)
+                .map(|(&multiple, argument)| multiple as f64 * argument)
+                .sum();
+            (a + b * t) * argument.to_radians().sin()
+        })
+        .sum::<f64>()
+        * 1e-4
+}
+
+/// Apparent geocentric ecliptic longitude of the sun in degrees, at a Julian ephemeris day.
+pub fn sun_apparent_longitude(jde: f64) -> f64 {
+    let tau = (jde - J2000) / 365250.0;
+    let t = tau * 10.0;
+    let longitude = vsop(EARTH_L, tau).to_degrees() + 180.0;
+    let radius = vsop(EARTH_R, tau);
+
+    // Conversion to the FK5 system
+    let fk5 = -0.09033;
+    let nutation = nutation_in_longitude(t);
+    // Annual aberration
+    let aberration = -20.4898 / radius;
+
+    (longitude + (fk5 + nutation + aberration) / 3600.0).rem_euclid(360.0)
+}
+
+/// The instant when the apparent longitude of the sun reaches `longitude` degrees, nearest to
+/// the Julian day `guess`.
+pub fn sun_longitude_instant(longitude: f64, guess: f64) -> f64 {
+    let mut jde = ut_to_tt(guess);
+    for _ in 0..10 {
+        let difference =
+            (longitude - sun_apparent_longitude(jde) + 180.0).rem_euclid(360.0) - 180.0;
+        let step = difference * TROPICAL_YEAR / 360.0;
+        jde += step;
+        if step.abs() < 1e-7 {
+            break;
+        }
+    }
+    tt_to_ut(jde)
+}
+
+/// Apparent geocentric ecliptic longitude of the moon in degrees, at a Julian ephemeris day.
+pub fn moon_apparent_longitude(jde: f64) -> f64 {
+    let t = (jde - J2000) / 36525.0;
+    let l = polynomial(
+        &[218.3164477, 481267.88123421, -0.0015786, 1.0 / 538841.0, -1.0 / 65194000.0],
+        t,
+    );
+    let arguments = [
+        polynomial(
+            &[297.8501921, 445267.1114034, -0.0018819, 1.0 / 545868.0, -1.0 / 113065000.0],
+            t,
+        ),
+        polynomial(
+            &[357.5291092, 35999.0502909, -0.0001536, 1.0 / 24490000.0],
+            t,
+        ),
+        polynomial(
+            &[134.9633964, 477198.8675055, 0.0087414, 1.0 / 69699.0, -1.0 / 14712000.0],
+            t,
+        ),
+        polynomial(
+            &[93.2720950, 483202.0175233, -0.0036539, -1.0 / 3526000.0, 1.0 / 863310000.0],
+            t,
+        ),
+    ];
+    // Decreasing eccentricity of the orbit of the earth
+    let e = polynomial(&[1.0, -0.002516, -0.0000074], t);
+    let periodic: f64 = MOON_L
+        .iter()
+        .map(|(multiples, coefficient)| {
+            let argument: f64 = multiples
+                .iter()
+                .zip(arguments)
+                .map(|(&multiple, argument)| multiple as f64 * argument)
+                .sum();
+            coefficient * e.powi(multiples[1].abs() as i32) * argument.to_radians().sin()
+        })
+        .sum();
+    // Actions of Venus and Jupiter, and the flattening of the earth
+    let additive = [
+        (3958.0, 119.75 + 131.849 * t),
+        (1962.0, l - arguments[3]),
+        (318.0, 53.09 + 479264.290 * t),
+    ]
+    .iter()
+    .map(|(coefficient, angle)| coefficient * angle.to_radians().sin())
+    .sum::<f64>();
+
+    (l + (periodic + additive) * 1e-6 + nutation_in_longitude(t) / 3600.0).rem_euclid(360.0)
+}
+
+/// Elongation of the moon east of the sun in degrees, from 0 at the new moon to 360, at a Julian
+/// day.
+pub fn moon_elongation(jd: f64) -> f64 {
+    let jde = ut_to_tt(jd);
+    (moon_apparent_longitude(jde) - sun_apparent_longitude(jde)).rem_euclid(360.0)
+}
+
+/// Number of lunations from the new moon of 2000-01-06 to the one nearest to a Julian day.
+pub fn lunation(jd: f64) -> i32 {
+    ((jd - 2451550.09766) / SYNODIC_MONTH).round() as i32
+}
+
+/// The instant of the new moon of a lunation, counted from the new moon of 2000-01-06.
+pub fn new_moon(lunation: i32) -> f64 {
+    moon_phase(lunation, 0)
+}
+
+/// The instant of a principal phase of the moon in a lunation counted from the new moon of
+/// 2000-01-06, where `phase` counts quarters from 0 for the new moon to 3 for the last quarter.
+pub fn moon_phase(lunation: i32, phase: u8) -> f64 {
+    let k = lunation as f64 + phase as f64 / 4.0;
+    let t = k / 1236.85;
+    let jde = polynomial(
+        &[2451550.09766 + 29.530588861 * k, 0.0, 0.00015437, -0.000000150, 0.00000000073],
+        t,
+    );
+    let e = polynomial(&[1.0, -0.002516, -0.0000074], t);
+    let m = polynomial(&[2.5534 + 29.10535670 * k, 0.0, -0.0000014, -0.00000011], t).to_radians();
+    let mp = polynomial(
+        &[201.5643 + 385.81693528 * k, 0.0, 0.0107582, 0.00001238, -0.000000058],
+        t,
+    )
+    .to_radians();
+    let f = polynomial(
+        &[160.7108 + 390.67050284 * k, 0.0, -0.0016118, -0.00000227, 0.000000011],
+        t,
+    )
+    .to_radians();
+    let omega =
+        polynomial(&[124.7746 - 1.56375588 * k, 0.0, 0.0020672, 0.00000215], t).to_radians();
+
+    let correction = match phase {
+        0 => {
+            -0.40720 * mp.sin()
+                + 0.17241 * e * m.sin()
+                + 0.01608 * (2.0 * mp).sin()
+                + 0.01039 * (2.0 * f).sin()
+                + 0.00739 * e * (mp - m).sin()
+                - 0.00514 * e * (mp + m).sin()
+                + 0.00208 * e * e * (2.0 * m).sin()
+                - 0.00111 * (mp - 2.0 * f).sin()
+                - 0.00057 * (mp + 2.0 * f).sin()
+                + 0.00056 * e * (2.0 * mp + m).sin()
+                - 0.00042 * (3.0 * mp).sin()
+                + 0.00042 * e * (m + 2.0 * f).sin()
+                + 0.00038 * e * (m - 2.0 * f).sin()
+                - 0.00024 * e * (2.0 * mp - m).sin()
+                - 0.00017 * omega.sin()
+                - 0.00007 * (mp + 2.0 * m).sin()
+                + 0.00004 * (2.0 * mp - 2.0 * f).sin()
+                + 0.00004 * (3.0 * m).sin()
+                + 0.00003 * (mp + m - 2.0 * f).sin()
+                + 0.00003 * (2.0 * mp + 2.0 * f).sin()
+                - 0.00003 * (mp + m + 2.0 * f).sin()
+                + 0.00003 * (mp - m + 2.0 * f).sin()
+                - 0.00002 * (mp - m - 2.0 * f).sin()
+                - 0.00002 * (3.0 * mp + m).sin()
+                + 0.00002 * (4.0 * mp).sin()
+        }
+        2 => {
+            -0.40614 * mp.sin()
+                + 0.17302 * e * m.sin()
+                + 0.01614 * (2.0 * mp).sin()
+                + 0.01043 * (2.0 * f).sin()
+                + 0.00734 * e * (mp - m).sin()
+                - 0.00515 * e * (mp + m).sin()
+                + 0.00209 * e * e * (2.0 * m).sin()
+                - 0.00111 * (mp - 2.0 * f).sin()
+                - 0.00057 * (mp + 2.0 * f).sin()
+                + 0.00056 * e * (2.0 * mp + m).sin()
+                - 0.00042 * (3.0 * mp).sin()
+                + 0.00042 * e * (m + 2.0 * f).sin()
+                + 0.00038 * e * (m - 2.0 * f).sin()
+                - 0.00024 * e * (2.0 * mp - m).sin()
+                - 0.00017 * omega.sin()
+                - 0.00007 * (mp + 2.0 * m).sin()
+                + 0.00004 * (2.0 * mp - 2.0 * f).sin()
+                + 0.00004 * (3.0 * m).sin()
+                + 0.00003 * (mp + m - 2.0 * f).sin()
+                + 0.00003 * (2.0 * mp + 2.0 * f).sin()
+                - 0.00003 * (mp + m + 2.0 * f).sin()
+                + 0.00003 * (mp - m + 2.0 * f).sin()
+                - 0.00002 * (mp - m - 2.0 * f).sin()
+                - 0.00002 * (3.0 * mp + m).sin()
+                + 0.00002 * (4.0 * mp).sin()
+        }
+        _ => {
+            let quarter = -0.62801 * mp.sin() + 0.17172 * e * m.sin()
+                - 0.01183 * e * (mp + m).sin()
+                + 0.00862 * (2.0 * mp).sin()
+                + 0.00804 * (2.0 * f).sin()
+                + 0.00454 * e * (mp - m).sin()
+                + 0.00204 * e * e * (2.0 * m).sin()
+                - 0.00180 * (mp - 2.0 * f).sin()
+                - 0.00070 * (mp + 2.0 * f).sin()
+                - 0.00040 * (3.0 * mp).sin()
+                - 0.00034 * e * (2.0 * mp - m).sin()
+                + 0.00032 * e * (m + 2.0 * f).sin()
+                + 0.00032 * e * (m - 2.0 * f).sin()
+                - 0.00028 * e * e * (mp + 2.0 * m).sin()
+                + 0.00027 * e * (2.0 * mp + m).sin()
+                - 0.00017 * omega.sin()
+                - 0.00005 * (mp - m - 2.0 * f).sin()
+                + 0.00004 * (2.0 * mp + 2.0 * f).sin()
+                - 0.00004 * (mp + m + 2.0 * f).sin()
+                + 0.00004 * (mp - 2.0 * m).sin()
+                + 0.00003 * (mp + m - 2.0 * f).sin()
+                + 0.00003 * (3.0 * m).sin()
+                + 0.00002 * (2.0 * mp - 2.0 * f).sin()
+                + 0.00002 * (mp - m + 2.0 * f).sin()
+                - 0.00002 * (3.0 * mp + m).sin();
+            let w = 0.00306 - 0.00038 * e * m.cos() + 0.00026 * mp.cos() - 0.00002 * (mp - m).cos()
+                + 0.00002 * (mp + m).cos()
+                + 0.00002 * (2.0 * f).cos();
+            if phase == 1 {
+                quarter + w
+            } else {
+                quarter - w
+            }
+        }
+    };
+
+    let planetary: f64 = [
+        (0.000325, 299.77 + 0.107408 * k - 0.009173 * t * t),
+        (0.000165, 251.88 + 0.016321 * k),
+        (0.000164, 251.83 + 26.651886 * k),
+        (0.000126, 349.42 + 36.412478 * k),
+        (0.000110, 84.66 + 18.206239 * k),
+        (0.000062, 141.74 + 53.303771 * k),
+        (0.000060, 207.14 + 2.453732 * k),
+        (0.000056, 154.84 + 7.306860 * k),
+        (0.000047, 34.52 + 27.261239 * k),
+        (0.000042, 207.19 + 0.121824 * k),
+        (0.000040, 291.34 + 1.844379 * k),
+        (0.000037, 161.72 + 24.198154 * k),
+        (0.000035, 239.56 + 25.513099 * k),
+        (0.000023, 331.55 + 3.592518 * k),
+    ]
+    .iter()
+    .map(|(coefficient, angle)| coefficient * (angle % 360.0).to_radians().sin())
+    .sum();
+
+    tt_to_ut(jde + correction + planetary)
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    // Examples 22.a, 25.b, 47.a and 49.a of Astronomical Algorithms
+    assert!((nutation_in_longitude((2446895.5 - J2000) / 36525.0) + 3.788).abs() < 0.01);
+    let longitude = sun_apparent_longitude(2448908.5);
+    assert!((longitude - (199.0 + 54.0 / 60.0 + 21.818 / 3600.0)).abs() < 1.0 / 3600.0);
+    // Example 47.a: the nutation in longitude is 16.6 arcseconds on 1992-04-12
+    let longitude = moon_apparent_longitude(2448724.5);
+    assert!((longitude - 133.167265).abs() < 1e-5);
+    let jde = ut_to_tt(new_moon(-283));
+    assert!((jde - 2443192.65118).abs() < 1e-4);
+    assert_eq!(lunation(jde), -283);
+    assert_eq!(local_date(jde, 0), NaiveDate::from_ymd_opt(1977, 2, 18));
+    // Spring equinox of 2000: 2000-03-20T07:35Z
+    let jd = sun_longitude_instant(
+        0.0,
+        julian_day(NaiveDate::from_ymd_opt(2000, 3, 20).unwrap()),
+    );
+    assert!((jd - 2451623.816).abs() < 2.0 / 1440.0);
+}
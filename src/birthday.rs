@@ -0,0 +1,115 @@
+//! Lunar birthdays and anniversaries in the Gregorian calendar.
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::{
+    chinese_date::ChineseYear,
+    events::{resolve, Day30Policy, EventDay, LeapPolicy},
+    ChineseDate,
+};
+
+/// A birthday or an anniversary kept on the lunar date of the day it commemorates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Birthday {
+    pub birth: ChineseDate,
+    pub leap_policy: LeapPolicy,
+    pub day_30_policy: Day30Policy,
+}
+
+impl Birthday {
+    /// A birthday kept in the leap month for people born in one, in the years it recurs, and on the
+    /// 29th day in short months for people born on the 30th.
+    pub fn new(birth: ChineseDate) -> Self {
+        Self {
+            birth,
+            leap_policy: if birth.leap() {
+                LeapPolicy::Leap
+            } else {
+                LeapPolicy::Regular
+            },
+            day_30_policy: Day30Policy::MoveTo29,
+        }
+    }
+    /// The birthdays in a Chinese year after the one of the birth.
+    pub fn in_year(&self, year: ChineseYear) -> Vec<NaiveDate> {
+        if year.get() <= self.birth.year() {
+            return Vec::new();
+        }
+        resolve(
+            year,
+            self.birth.month(),
+            EventDay::Day(self.birth.day()),
+            self.leap_policy,
+            self.day_30_policy,
+            None,
+        )
+        .iter()
+        .map(ChineseDate::to_gregorian)
+        .collect()
+    }
+    /// The birthdays on or after a date, as long as the Chinese calendar lasts.
+    pub fn occurrences_from(&self, date: NaiveDate) -> impl Iterator<Item = NaiveDate> + '_ {
+        // The Chinese year before the Gregorian one lasts until its Chunjie
+        (date.year() - 1..)
+            .map_while(ChineseYear::new)
+            .flat_map(|year| self.in_year(year))
+            .filter(move |birthday| *birthday >= date)
+    }
+    /// The first birthday after a date.
+    pub fn next_after(&self, date: NaiveDate) -> Option<NaiveDate> {
+        self.occurrences_from(date.succ_opt()?).next()
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    use crate::date;
+
+    let birthday = Birthday::new(ChineseDate::new(1990, 8, false, 15).unwrap());
+    assert_eq!(birthday.in_year(ChineseYear::new(1990).unwrap()), []);
+    assert_eq!(
+        birthday.next_after(date(2024, 9, 16)),
+        Some(date(2024, 9, 17))
+    );
+    assert_eq!(
+        birthday.next_after(date(2024, 9, 17)),
+        Some(date(2025, 10, 6))
+    );
+    assert_eq!(
+        birthday.occurrences_from(date(1980, 1, 1)).next(),
+        Some(date(1991, 9, 22))
+    );
+
+    // Born in the leap 2nd month of 2023, kept in the 2nd month until it recurs
+    let birthday = Birthday::new(ChineseDate::new(2023, 2, true, 15).unwrap());
+    assert_eq!(
+        birthday
+            .occurrences_from(date(2024, 1, 1))
+            .take(2)
+            .collect::<Vec<_>>(),
+        [date(2024, 3, 24), date(2025, 3, 14)]
+    );
+    let birthday = Birthday {
+        leap_policy: LeapPolicy::Regular,
+        ..birthday
+    };
+    assert_eq!(
+        birthday.next_after(date(2024, 1, 1)),
+        Some(date(2024, 3, 24))
+    );
+
+    // Born on the 30th of the 12th month of 2023, which has 29 days in 2024
+    let birthday = Birthday::new(ChineseDate::new(2023, 12, false, 30).unwrap());
+    assert_eq!(
+        birthday.next_after(date(2024, 2, 9)),
+        Some(date(2025, 1, 28))
+    );
+    let birthday = Birthday {
+        day_30_policy: Day30Policy::Skip,
+        ..birthday
+    };
+    let next = birthday.next_after(date(2024, 2, 9)).unwrap();
+    assert!(next > date(2025, 1, 28));
+    assert_eq!(ChineseDate::from_gregorian(&next).unwrap().day(), 30);
+}
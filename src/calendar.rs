@@ -1,18 +1,65 @@
 use crate::{
-    festivals::Festival, is_weekend, language::{Language, MonthTitle}, ChineseDate, SolarTerm
+    chinese_date::BEIJING_OFFSET,
+    events::Registry,
+    festivals::Festival,
+    ganzhi::Ganzhi,
+    holidays::{Arrangement, Holiday, Holidays, Region, Regions},
+    is_weekend,
+    japanese_date::{JapaneseDate, Rokuyo, JAPAN_OFFSET},
+    korean_date::{KoreanDate, KOREA_OFFSET},
+    language::{Language, MonthTitle, ShortTranslate, Translate},
+    moon_phase::MoonPhase,
+    pentad::Pentad,
+    vietnamese_date::{VietnameseDate, VIETNAM_OFFSET},
+    zajie::Period,
+    ChineseDate, SolarTerm,
 };
 use chrono::{Datelike, Month, NaiveDate, Weekday};
+use std::{
+    cmp::Reverse,
+    fmt::{Formatter, Result as FmtResult},
+    sync::Arc,
+};
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Options {
     pub language: Language,
     pub enable_chinese: bool,
+    pub variant: Variant,
+    /// Whether to annotate days with their rokuyō.
+    pub rokuyo: bool,
+    /// Whether to annotate days with their ganzhi.
+    pub day_ganzhi: bool,
+    /// Whether to mark the principal phases of the moon.
+    pub moon_phase: bool,
+    /// Whether to mark the dog days and the nine-nines.
+    pub periods: bool,
+    /// Whether to mark the days the 72 pentads begin.
+    pub pentads: bool,
+    /// The registry whose events are marked, if any.
+    pub events: Option<Arc<Registry>>,
+    /// Regions whose public holidays and adjusted working days are marked, overlaid, with the
+    /// sources added for them.
+    pub holidays: Holidays,
     pub start_on_monday: bool,
     pub week_number: bool,
     pub color: bool,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The lunisolar calendar shown next to the Gregorian one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Variant {
+    #[default]
+    Chinese,
+    /// The Vietnamese calendar, reckoned at UTC+7.
+    Vietnamese,
+    /// The Korean calendar, reckoned at UTC+9.
+    Korean,
+    /// The Japanese old calendar, reckoned at UTC+9.
+    Japanese,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Calendar {
     year: i32,
     pub month: Month,
@@ -26,15 +73,112 @@ pub struct Iter<'a> {
     day: u8,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[non_exhaustive]
 pub struct Cell {
     pub date: NaiveDate,
     pub today: bool,
     pub weekend: bool,
+    /// Date in the lunisolar calendar of [`Options::variant`].
     pub chinese_date: Option<ChineseDate>,
     pub solar_term: Option<SolarTerm>,
-    pub festival: Option<Festival>,
+    /// Whether the day is off for a public holiday in any region of [`Options::holidays`].
+    pub holiday: bool,
+    /// Whether the day is a working day, as weekdays besides holidays and weekend days made working
+    /// days in any region.
+    pub workday: bool,
+    /// Regions where the weekend day is made a working day.
+    pub workday_regions: Regions,
+    /// Festivals and observances of the day, from the highest priority.
+    pub annotations: Vec<Annotation>,
+    /// Labels of the events of [`Options::events`] on the day.
+    pub events: Vec<String>,
+    pub rokuyo: Option<Rokuyo>,
+    pub day_ganzhi: Option<Ganzhi>,
+    /// The principal phase of the moon beginning on the day, at the offset of
+    /// [`Options::variant`].
+    pub moon_phase: Option<MoonPhase>,
+    pub period: Option<Period>,
+    /// The pentad beginning on the day, at the offset of [`Options::variant`].
+    pub pentad: Option<Pentad>,
+}
+
+/// A festival or observance of a day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Annotation {
+    Festival(Festival),
+    SolarTerm(SolarTerm),
+    Period(Period),
+    MoonPhase(MoonPhase),
+    Holiday(Region, Holiday),
+}
+
+impl Annotation {
+    /// Priority for being shown under the date, from major festivals down to the phases of the
+    /// moon. Annotations of priority 0, as the days of a period after the first, are only listed.
+    pub fn priority(self) -> u8 {
+        match self {
+            Annotation::Festival(festival) if festival.category().major => 5,
+            Annotation::SolarTerm(_) => 4,
+            Annotation::Festival(_) => 3,
+            Annotation::Period(period) if period.day() == 1 => 2,
+            Annotation::MoonPhase(_) => 1,
+            Annotation::Period(_) | Annotation::Holiday(..) => 0,
+        }
+    }
+}
+
+impl Translate for Annotation {
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        match self {
+            Annotation::Festival(festival) => festival.translate(language, f),
+            Annotation::SolarTerm(solar_term) => solar_term.translate(language, f),
+            Annotation::Period(period) => period.translate(language, f),
+            Annotation::MoonPhase(moon_phase) => moon_phase.translate(language, f),
+            Annotation::Holiday(_, holiday) => holiday.translate(language, f),
+        }
+    }
+}
+
+impl ShortTranslate for Annotation {
+    fn short_translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        match self {
+            Annotation::Festival(festival) => festival.short_translate(language, f),
+            Annotation::SolarTerm(solar_term) => solar_term.short_translate(language, f),
+            Annotation::Period(period) => period.short_translate(language, f),
+            Annotation::MoonPhase(moon_phase) => moon_phase.short_translate(language, f),
+            Annotation::Holiday(_, holiday) => holiday.translate(language, f),
+        }
+    }
+}
+
+impl Cell {
+    /// The holiday arrangements of the day in each region where it departs from the weekly rest
+    /// days, the holidays first.
+    pub fn arrangements(&self) -> impl Iterator<Item = (Region, Arrangement)> + '_ {
+        self.annotations
+            .iter()
+            .filter_map(|annotation| match annotation {
+                Annotation::Holiday(region, holiday) => {
+                    Some((*region, Arrangement::Holiday(*holiday)))
+                }
+                _ => None,
+            })
+            .chain(
+                self.workday_regions
+                    .iter()
+                    .map(|region| (region, Arrangement::Workday)),
+            )
+    }
+    pub fn festivals(&self) -> impl Iterator<Item = Festival> + '_ {
+        self.annotations
+            .iter()
+            .filter_map(|annotation| match annotation {
+                Annotation::Festival(festival) => Some(*festival),
+                _ => None,
+            })
+    }
 }
 
 impl Calendar {
@@ -76,6 +220,119 @@ impl Calendar {
         }
     }
 
+    /// The cell of a date, with the lunisolar calendar reckoned as set in the options.
+    pub fn cell(&self, date: NaiveDate) -> Cell {
+        let variant = self.options.variant;
+        let (chinese_date, solar_term, festivals) = if self.options.enable_chinese {
+            let (chinese_date, festival) = match variant {
+                Variant::Chinese => {
+                    let chinese_date = ChineseDate::from_gregorian(&date);
+                    (
+                        chinese_date,
+                        chinese_date.and_then(Festival::from_chinese_date),
+                    )
+                }
+                Variant::Vietnamese => {
+                    let vietnamese_date = VietnameseDate::from_gregorian(&date);
+                    (
+                        vietnamese_date.map(|date| date.0),
+                        vietnamese_date.and_then(Festival::from_vietnamese_date),
+                    )
+                }
+                Variant::Korean => {
+                    let korean_date = KoreanDate::from_gregorian(&date);
+                    (
+                        korean_date.map(|date| date.0),
+                        korean_date.and_then(Festival::from_korean_date),
+                    )
+                }
+                Variant::Japanese => {
+                    let japanese_date = JapaneseDate::from_gregorian(&date);
+                    (
+                        japanese_date.map(|date| date.0),
+                        japanese_date.and_then(Festival::from_japanese_date),
+                    )
+                }
+            };
+            (
+                chinese_date,
+                SolarTerm::from_date_at(date, variant.offset()),
+                [festival, Festival::from_gregorian_date(&date, variant)],
+            )
+        } else {
+            (None, None, [None, None])
+        };
+        let rokuyo = if self.options.rokuyo {
+            JapaneseDate::from_gregorian(&date).map(|date| date.rokuyo())
+        } else {
+            None
+        };
+        let moon_phase = if self.options.moon_phase {
+            MoonPhase::from_date_at(date, variant.offset())
+        } else {
+            None
+        };
+        let period = if self.options.periods {
+            Period::from_date(&date)
+        } else {
+            None
+        };
+        let mut holidays = Vec::new();
+        let mut workday_regions = Regions::default();
+        for (region, arrangement) in self.options.holidays.arrangements(date) {
+            match arrangement {
+                Arrangement::Holiday(holiday) => {
+                    holidays.push(Annotation::Holiday(region, holiday))
+                }
+                Arrangement::Workday => workday_regions.insert(region),
+            }
+        }
+        let weekend = is_weekend(date.weekday());
+        let holiday = !holidays.is_empty();
+        let mut annotations: Vec<_> = festivals
+            .into_iter()
+            .flatten()
+            .map(Annotation::Festival)
+            .chain(solar_term.map(Annotation::SolarTerm))
+            .chain(period.map(Annotation::Period))
+            .chain(moon_phase.map(Annotation::MoonPhase))
+            .chain(holidays)
+            .collect();
+        annotations.sort_by_key(|annotation| Reverse(annotation.priority()));
+        Cell {
+            date,
+            today: self.today == Some(date),
+            weekend,
+            chinese_date,
+            solar_term,
+            holiday,
+            workday: !holiday && (!weekend || !workday_regions.is_empty()),
+            workday_regions,
+            annotations,
+            events: self
+                .options
+                .events
+                .as_ref()
+                .map_or_else(Vec::new, |registry| {
+                    // Resolved in the calendar of the variant, as the lunar date of the cell
+                    let offset = (variant != Variant::Chinese).then(|| variant.offset());
+                    registry
+                        .on_at(date, offset)
+                        .map(|event| event.label.clone())
+                        .collect()
+                }),
+            rokuyo,
+            day_ganzhi: self.options.day_ganzhi.then(|| Ganzhi::of_day(&date)),
+            moon_phase,
+            period,
+            pentad: if self.options.pentads {
+                Pentad::from_date_at(date, variant.offset())
+            } else {
+                None
+            },
+        }
+    }
+
     pub fn title(&self) -> MonthTitle {
         MonthTitle {
             year: self.year,
@@ -85,6 +342,18 @@ impl Calendar {
     }
 }
 
+impl Variant {
+    /// UTC offset the calendar is reckoned at, in seconds.
+    pub fn offset(self) -> i32 {
+        match self {
+            Variant::Chinese => BEIJING_OFFSET,
+            Variant::Vietnamese => VIETNAM_OFFSET,
+            Variant::Korean => KOREA_OFFSET,
+            Variant::Japanese => JAPAN_OFFSET,
+        }
+    }
+}
+
 impl Iterator for Iter<'_> {
     type Item = (u32, [Option<Cell>; 7]);
     fn next(&mut self) -> Option<Self::Item> {
@@ -100,33 +369,11 @@ impl Iterator for Iter<'_> {
         } else {
             (Weekday::Sun, Weekday::Sat)
         };
-        let today_day = self.calendar.today.and_then(|today| {
-            (self.calendar.year == today.year()
-                && self.calendar.month.number_from_month() == today.month())
-            .then(|| today.day())
-        });
 
-        let mut array = [Option::<Cell>::None; 7];
+        let mut array: [Option<Cell>; 7] = Default::default();
         loop {
             let weekday = date.weekday();
-            let (chinese_date, solar_term, festival) = if self.calendar.options.enable_chinese {
-                let chinese_date = ChineseDate::from_gregorian(&date);
-                (
-                    chinese_date,
-                    SolarTerm::from_date(&date),
-                    chinese_date.and_then(Festival::from_chinese_date)
-                )
-            } else {
-                (None, None, None)
-            };
-            array[weekday.days_since(start_of_week) as usize] = Some(Cell {
-                date,
-                today: today_day.is_some_and(|today| today == date.day()),
-                weekend: is_weekend(date.weekday()),
-                chinese_date,
-                solar_term,
-                festival,
-            });
+            array[weekday.days_since(start_of_week) as usize] = Some(self.calendar.cell(date));
             self.day += 1;
             date = if let Some(date) = date
                 .succ_opt()
@@ -144,11 +391,11 @@ impl Iterator for Iter<'_> {
             if start_on_monday {
                 array
                     .iter()
-                    .find_map(|cell| cell.map(|cell| cell.date.iso_week().week()))
+                    .find_map(|cell| cell.as_ref().map(|cell| cell.date.iso_week().week()))
                     .unwrap_or_default()
-            } else if let Some(monday) = array[1] {
+            } else if let Some(monday) = &array[1] {
                 monday.date.iso_week().week()
-            } else if let Some(sunday) = array[0] {
+            } else if let Some(sunday) = &array[0] {
                 if let Some(monday) = sunday.date.succ_opt() {
                     monday.iso_week().week()
                 } else {
@@ -157,7 +404,7 @@ impl Iterator for Iter<'_> {
             } else {
                 array
                     .iter()
-                    .find_map(|cell| cell.map(|cell| cell.date.iso_week().week()))
+                    .find_map(|cell| cell.as_ref().map(|cell| cell.date.iso_week().week()))
                     .unwrap_or_default()
             }
         } else {
@@ -177,12 +424,20 @@ fn test() {
         options: Options {
             color: false,
             enable_chinese: true,
+            variant: Variant::Chinese,
+            rokuyo: false,
+            day_ganzhi: false,
+            moon_phase: false,
+            periods: false,
+            pentads: false,
+            events: None,
+            holidays: Holidays::default(),
             language: Language::English,
             start_on_monday: false,
             week_number: true,
         },
     };
-    let mut array = [Option::<Cell>::None; 35];
+    let mut array: [Option<Cell>; 35] = std::array::from_fn(|_| None);
     for day in 1u32..=31 {
         let date = NaiveDate::from_ymd_opt(2025, 1, day).unwrap();
         let chinese_date = ChineseDate::from_gregorian(&date);
@@ -192,16 +447,177 @@ fn test() {
             today: day == 1,
             chinese_date,
             solar_term: SolarTerm::from_date(&date),
-            festival: chinese_date.and_then(Festival::from_chinese_date),
+            holiday: false,
+            workday: !is_weekend(date.weekday()),
+            workday_regions: Regions::default(),
+            annotations: chinese_date
+                .and_then(Festival::from_chinese_date)
+                .map(Annotation::Festival)
+                .into_iter()
+                .chain(SolarTerm::from_date(&date).map(Annotation::SolarTerm))
+                .collect(),
+            events: Vec::new(),
+            rokuyo: None,
+            day_ganzhi: None,
+            moon_phase: None,
+            period: None,
+            pentad: None,
         });
     }
     for (a, b) in calendar.iter().zip(
         [1u32, 2, 3, 4, 5].into_iter().zip(
             array
                 .chunks(7)
-                .map(|chunk| <[Option<Cell>; 7]>::try_from(chunk).unwrap()),
+                .map(|chunk| <[Option<Cell>; 7]>::try_from(chunk.to_vec()).unwrap()),
         ),
     ) {
         assert_eq!(a, b);
     }
+
+    let mut calendar = Calendar::new(2007, Month::February, None, calendar.options).unwrap();
+    let date = NaiveDate::from_ymd_opt(2007, 2, 17).unwrap();
+    assert_eq!(
+        calendar.cell(date).festivals().next(),
+        Some(Festival::Chuxi)
+    );
+    calendar.options.variant = Variant::Vietnamese;
+    let cell = calendar.cell(date);
+    assert_eq!(cell.chinese_date, ChineseDate::new(2007, 1, false, 1));
+    assert_eq!(cell.festivals().next(), Some(Festival::Chunjie));
+
+    let mut calendar = Calendar::new(1997, Month::February, None, calendar.options).unwrap();
+    let date = NaiveDate::from_ymd_opt(1997, 2, 7).unwrap();
+    assert_eq!(
+        calendar.cell(date).festivals().next(),
+        Some(Festival::Chunjie)
+    );
+    calendar.options.variant = Variant::Korean;
+    assert_eq!(calendar.cell(date).festivals().next(), None);
+    assert_eq!(
+        calendar.cell(date.succ_opt().unwrap()).festivals().next(),
+        Some(Festival::Chunjie)
+    );
+    calendar.options.rokuyo = true;
+    assert_eq!(calendar.cell(date).rokuyo, Some(Rokuyo::Taian));
+    assert_eq!(calendar.cell(date).day_ganzhi, None);
+    calendar.options.day_ganzhi = true;
+    let ganzhi = calendar.cell(date).day_ganzhi.unwrap();
+    assert_eq!(
+        Some(ganzhi),
+        ChineseDate::new(1997, 1, false, 1).map(|date| date.day_ganzhi())
+    );
+    assert_eq!(
+        calendar.cell(date.succ_opt().unwrap()).day_ganzhi,
+        Some(ganzhi + 1)
+    );
+
+    // The first quarter of 2024-08-12 at 15:19 UTC is past midnight in Korea
+    let mut calendar = Calendar::new(2024, Month::August, None, calendar.options).unwrap();
+    calendar.options.moon_phase = true;
+    calendar.options.variant = Variant::Chinese;
+    let date = NaiveDate::from_ymd_opt(2024, 8, 12).unwrap();
+    assert_eq!(
+        calendar.cell(date).moon_phase,
+        Some(MoonPhase::FirstQuarter)
+    );
+    calendar.options.variant = Variant::Korean;
+    assert_eq!(calendar.cell(date).moon_phase, None);
+    assert_eq!(
+        calendar.cell(date.succ_opt().unwrap()).moon_phase,
+        Some(MoonPhase::FirstQuarter)
+    );
+
+    calendar.options.periods = true;
+    assert_eq!(
+        calendar
+            .cell(NaiveDate::from_ymd_opt(2024, 7, 15).unwrap())
+            .period,
+        Some(Period::Fu(crate::zajie::Fu::Chufu, 1))
+    );
+
+    calendar.options.variant = Variant::Chinese;
+    let date = NaiveDate::from_ymd_opt(2024, 4, 3).unwrap();
+    assert_eq!(
+        calendar.cell(date).festivals().next(),
+        Some(Festival::Hanshijie)
+    );
+    assert_eq!(
+        calendar.cell(date.succ_opt().unwrap()).festivals().next(),
+        Some(Festival::Qingmingjie)
+    );
+
+    // Chunjie of 1992 is on Lichun and a new moon, in the fifth nine
+    let date = NaiveDate::from_ymd_opt(1992, 2, 4).unwrap();
+    assert_eq!(
+        calendar.cell(date).annotations,
+        [
+            Annotation::Festival(Festival::Chunjie),
+            Annotation::SolarTerm(SolarTerm::Lichun),
+            Annotation::MoonPhase(MoonPhase::NewMoon),
+            Annotation::Period(Period::Jiu(5, 9)),
+        ]
+    );
+    let date = NaiveDate::from_ymd_opt(2024, 7, 16).unwrap();
+    let annotations = calendar.cell(date).annotations;
+    assert_eq!(annotations.len(), 1);
+    assert_eq!(annotations[0].priority(), 0);
+
+    calendar.options.holidays = Region::Mainland.into();
+    let date = NaiveDate::from_ymd_opt(2024, 9, 29).unwrap();
+    let cell = calendar.cell(date);
+    assert!(cell.weekend && cell.workday && !cell.holiday);
+    let cell = calendar.cell(NaiveDate::from_ymd_opt(2024, 10, 1).unwrap());
+    assert!(cell.holiday && !cell.workday);
+    assert!(cell
+        .annotations
+        .contains(&Annotation::Holiday(Region::Mainland, Holiday::NationalDay)));
+    // 2024-09-18 is a holiday in Hong Kong but not in mainland China, and 09-14 a working day in
+    // mainland China only
+    calendar.options.holidays = Regions::from_iter([Region::Mainland, Region::HongKong]).into();
+    let cell = calendar.cell(NaiveDate::from_ymd_opt(2024, 9, 18).unwrap());
+    assert!(cell.holiday && !cell.workday);
+    assert_eq!(
+        cell.arrangements().collect::<Vec<_>>(),
+        [(
+            Region::HongKong,
+            Arrangement::Holiday(Holiday::DayAfterZhongqiu)
+        )]
+    );
+    let cell = calendar.cell(NaiveDate::from_ymd_opt(2024, 9, 14).unwrap());
+    assert!(cell.workday);
+    assert_eq!(
+        cell.arrangements().collect::<Vec<_>>(),
+        [(Region::Mainland, Arrangement::Workday)]
+    );
+
+    use crate::events::{Event, EventDay};
+    let mut registry = Registry::new();
+    registry.add(Event::new("Memorial", 8, EventDay::Day(10)).unwrap());
+    calendar.options.events = Some(Arc::new(registry));
+    let date = NaiveDate::from_ymd_opt(2024, 9, 12).unwrap();
+    assert_eq!(calendar.cell(date).events, ["Memorial"]);
+    assert!(calendar.cell(date.succ_opt().unwrap()).events.is_empty());
+
+    // Events fall on the lunar dates of the variant, as Chunjie of 2007 and 1997 above
+    let mut registry = Registry::new();
+    registry.add(Event::new("First", 1, EventDay::Day(1)).unwrap());
+    calendar.options.events = Some(Arc::new(registry));
+    let date = NaiveDate::from_ymd_opt(2007, 2, 17).unwrap();
+    calendar.options.variant = Variant::Chinese;
+    assert!(calendar.cell(date).events.is_empty());
+    calendar.options.variant = Variant::Vietnamese;
+    assert_eq!(calendar.cell(date).events, ["First"]);
+    let date = NaiveDate::from_ymd_opt(1997, 2, 7).unwrap();
+    calendar.options.variant = Variant::Chinese;
+    assert_eq!(calendar.cell(date).events, ["First"]);
+    for variant in [Variant::Korean, Variant::Japanese] {
+        calendar.options.variant = variant;
+        assert!(calendar.cell(date).events.is_empty());
+        assert_eq!(calendar.cell(date.succ_opt().unwrap()).events, ["First"]);
+    }
+
+    calendar.options.pentads = true;
+    let date = NaiveDate::from_ymd_opt(2024, 8, 12).unwrap();
+    assert_eq!(calendar.cell(date).pentad, Pentad::new(SolarTerm::Liqiu, 1));
+    assert_eq!(calendar.cell(date.succ_opt().unwrap()).pentad, None);
 }
@@ -1,6 +1,37 @@
-use chrono::NaiveDate;
+use std::{collections::BTreeMap, ops::RangeInclusive, sync::Mutex};
 
-use crate::data::{CHUNJIE, DATA};
+use chrono::{DateTime, Datelike, Days, FixedOffset, NaiveDate, Offset, TimeZone};
+
+use crate::{
+    astronomy::{julian_day, local_date, lunation, new_moon, sun_longitude_instant},
+    data::{CHUNJIE, DATA},
+    ganzhi::Ganzhi,
+};
+
+/// Years supported by the conversions.
+///
+/// Years in `1900..=2100` are read from tables, the others are reckoned astronomically with the
+/// modern rules, which may differ from the historical calendars before 1645.
+pub const YEARS: RangeInclusive<i32> = -1000..=3000;
+
+/// UTC offset of Beijing time, in seconds.
+pub const BEIJING_OFFSET: i32 = 8 * 3600;
+/// UTC offset of the local mean time of Beijing (116°25′E), in seconds, which the calendar used
+/// before 1929.
+pub const BEIJING_MEAN_OFFSET: i32 = 7 * 3600 + 45 * 60 + 40;
+
+/// The meridian whose civil time the calendar is reckoned in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Meridian {
+    /// Beijing time (UTC+8), the standard of the Chinese calendar, in every year.
+    #[default]
+    Beijing,
+    /// The UTC offset of the converted time, as the Vietnamese and Korean calendars do.
+    Local,
+    /// The local mean time of Beijing before 1929 and Beijing time since, as the calendar was
+    /// historically reckoned.
+    Historical,
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ChineseDate {
@@ -18,27 +49,165 @@ pub struct ChineseMonth(pub u8, pub bool);
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ChineseDay(pub u8);
 
-fn data(year: i32) -> Option<u32> {
-    (1900..=2100)
-        .contains(&year)
-        .then(|| DATA[year as usize - 1900])
+/// UTC offset of Beijing in a Gregorian year, which was the local mean time before 1929.
+pub(crate) fn historical_offset(year: i32) -> i32 {
+    if year < 1929 {
+        BEIJING_MEAN_OFFSET
+    } else {
+        BEIJING_OFFSET
+    }
 }
 
-fn short_or_long(year: ChineseYear) -> Option<u16> {
-    data(year.get()).map(|data| {
-        let leap_month = data as u8 & 0x0f;
-        (if leap_month > 0 {
-            (data >> 3) & !((1 << (13 - leap_month)) - 1) // Months before the leap month
-                    | ((data >> 16 & 1) << (12 - leap_month)) // The leap month
-                    | (data >> 4) & ((1 << (12 - leap_month)) - 1) // Monthes after the leap month
+/// Instant of the winter solstice (Dongzhi) of a Gregorian year, in Julian day.
+fn dongzhi(year: i32) -> Option<f64> {
+    let guess = julian_day(NaiveDate::from_ymd_opt(year, 12, 22)?);
+    Some(sun_longitude_instant(270.0, guess))
+}
+
+/// Month starts of the sui from the 11th month of a Gregorian year to the 11th month of the next,
+/// as (start date, month, leap). The last item is the start of the next 11th month.
+fn sui(year: i32, offset: i32) -> Option<Vec<(NaiveDate, u8, bool)>> {
+    let start = local_date(dongzhi(year)?, offset)?;
+    let end = local_date(dongzhi(year + 1)?, offset)?;
+    let new_moon_date = |k| local_date(new_moon(k), offset);
+
+    let mut k = lunation(julian_day(start));
+    while new_moon_date(k)? > start {
+        k -= 1;
+    }
+    while new_moon_date(k + 1)? <= start {
+        k += 1;
+    }
+    let mut starts = vec![new_moon_date(k)?];
+    loop {
+        k += 1;
+        let date = new_moon_date(k)?;
+        if date > end {
+            break;
+        }
+        starts.push(date);
+    }
+
+    let leap_index = if starts.len() == 14 {
+        // The first month without a principal term (zhongqi) is the leap month
+        let mut guess = julian_day(start);
+        let mut zhongqi = vec![start];
+        for i in 1..=12 {
+            guess += 30.4;
+            let longitude = (270 + i * 30) % 360;
+            zhongqi.push(local_date(
+                sun_longitude_instant(longitude as f64, guess),
+                offset,
+            )?);
+        }
+        starts.windows(2).position(|month| {
+            !zhongqi
+                .iter()
+                .any(|date| (month[0]..month[1]).contains(date))
+        })
+    } else {
+        None
+    };
+
+    let mut month = 10;
+    Some(
+        starts
+            .into_iter()
+            .enumerate()
+            .map(|(i, date)| {
+                let leap = leap_index == Some(i);
+                if !leap {
+                    month = month % 12 + 1;
+                }
+                (date, month, leap)
+            })
+            .collect(),
+    )
+}
+
+/// Reckons the month data of a Chinese year, in the format of [`DATA`], and the date of its
+/// Chunjie at a UTC offset in seconds, from new moons and principal terms.
+pub(crate) fn reckon(year: i32, offset: i32) -> Option<(u32, NaiveDate)> {
+    let previous = sui(year - 1, offset)?;
+    let current = sui(year, offset)?;
+    let chunjie = previous
+        .iter()
+        .position(|&(_, month, leap)| month == 1 && !leap)?;
+    let next_chunjie = current
+        .iter()
+        .position(|&(_, month, leap)| month == 1 && !leap)?;
+    let months = previous[chunjie..previous.len() - 1]
+        .iter()
+        .chain(&current[..=next_chunjie]);
+
+    let mut data = 0u32;
+    for (&(start, month, leap), &(end, _, _)) in months.clone().zip(months.skip(1)) {
+        let long = (end - start).num_days() == 30;
+        if leap {
+            data |= month as u32 | (long as u32) << 16;
         } else {
-            data >> 3 & 0x1ffe
-        }) as u16
-    })
+            data |= (long as u32) << (16 - month);
+        }
+    }
+    Some((data, previous[chunjie].0))
+}
+
+type Reckoned = BTreeMap<(i32, i32), Option<(u32, NaiveDate)>>;
+
+/// Years reckoned by [`reckon`], by year and UTC offset.
+static RECKONED: Mutex<Reckoned> = Mutex::new(BTreeMap::new());
+
+/// Month data and Chunjie of a year in the calendar reckoned at a UTC offset in seconds, or by
+/// default as the tables give it in `1900..=2100` and at the historical offset outside them.
+fn year_data(year: i32, offset: Option<i32>) -> Option<(u32, NaiveDate)> {
+    if offset.unwrap_or(BEIJING_OFFSET) == BEIJING_OFFSET && (1900..=2100).contains(&year) {
+        let index = year as usize - 1900;
+        Some((
+            DATA[index],
+            NaiveDate::from_yo_opt(year, CHUNJIE[index] as u32 + 1)?,
+        ))
+    } else if YEARS.contains(&year) {
+        let offset = offset.unwrap_or_else(|| historical_offset(year));
+        // Reckoning a year takes dozens of ephemeris solutions, so each is kept once done
+        let mut reckoned = RECKONED.lock().unwrap();
+        *reckoned
+            .entry((year, offset))
+            .or_insert_with(|| reckon(year, offset))
+    } else {
+        None
+    }
+}
+
+fn data(year: i32, offset: Option<i32>) -> Option<u32> {
+    year_data(year, offset).map(|(data, _)| data)
+}
+
+/// Date of Chunjie in a year.
+pub fn chunjie(year: ChineseYear) -> Option<NaiveDate> {
+    chunjie_at(year, None)
+}
+
+pub(crate) fn chunjie_at(year: ChineseYear, offset: Option<i32>) -> Option<NaiveDate> {
+    year_data(year.get(), offset).map(|(_, chunjie)| chunjie)
+}
+
+fn short_or_long(data: u32) -> u16 {
+    let leap_month = data as u8 & 0x0f;
+    (if leap_month > 0 {
+        (data >> 3) & !((1 << (13 - leap_month)) - 1) // Months before the leap month
+                | ((data >> 16 & 1) << (12 - leap_month)) // The leap month
+                | (data >> 4) & ((1 << (12 - leap_month)) - 1) // Monthes after the leap month
+    } else {
+        data >> 3 & 0x1ffe
+    }) as u16
 }
 
 pub fn ordinal_month(year: ChineseYear, month: ChineseMonth) -> Option<u8> {
-    let data = data(year.get()).unwrap();
+    ordinal_month_at(year, month, None)
+}
+
+fn ordinal_month_at(year: ChineseYear, month: ChineseMonth, offset: Option<i32>) -> Option<u8> {
+    let data = data(year.get(), offset)?;
     let leap_month = data as u8 & 0x0f;
     if month.leap() && month.month() != leap_month {
         return None;
@@ -54,17 +223,31 @@ pub fn ordinal_month(year: ChineseYear, month: ChineseMonth) -> Option<u8> {
 }
 
 pub fn is_long_month(year: ChineseYear, month: ChineseMonth) -> Option<bool> {
-    ordinal_month(year, month).and_then(|ord_month| {
-        short_or_long(year).map(|short_long| short_long >> (12 - ord_month) & 1 > 0)
+    is_long_month_at(year, month, None)
+}
+
+fn is_long_month_at(year: ChineseYear, month: ChineseMonth, offset: Option<i32>) -> Option<bool> {
+    ordinal_month_at(year, month, offset).and_then(|ord_month| {
+        data(year.get(), offset).map(|data| short_or_long(data) >> (12 - ord_month) & 1 > 0)
     })
 }
 
 pub fn days_of_chinese_month(year: ChineseYear, month: ChineseMonth) -> Option<u8> {
-    is_long_month(year, month).map(|long| if long { 30 } else { 29 })
+    days_of_chinese_month_at(year, month, None)
+}
+
+pub(crate) fn days_of_chinese_month_at(
+    year: ChineseYear, month: ChineseMonth, offset: Option<i32>,
+) -> Option<u8> {
+    is_long_month_at(year, month, offset).map(|long| if long { 30 } else { 29 })
 }
 
 pub fn leap_month(year: ChineseYear) -> u8 {
-    match data(year.0) {
+    leap_month_at(year, None)
+}
+
+pub(crate) fn leap_month_at(year: ChineseYear, offset: Option<i32>) -> u8 {
+    match data(year.0, offset) {
         Some(data) => data as u8 & 0x0f,
         None => 0,
     }
@@ -78,32 +261,42 @@ impl ChineseDate {
             day: ChineseDay::new(day)?,
         })
     }
-    pub fn from_gregorian(date: &impl chrono::Datelike) -> Option<Self> {
-        let mut year = date.year();
-        if !(1900..=2100).contains(&year) {
-            return None;
+    pub fn from_gregorian(date: &impl Datelike) -> Option<Self> {
+        let date = NaiveDate::from_ymd_opt(date.year(), date.month(), date.day())?;
+        Self::from_gregorian_at(date, None)
+    }
+    /// Converts a Gregorian date in the calendar reckoned at a UTC offset in seconds, or by default
+    /// for `None`.
+    pub(crate) fn from_gregorian_at(date: NaiveDate, offset: Option<i32>) -> Option<Self> {
+        let mut year = ChineseYear::new(date.year())?;
+        let mut chunjie = chunjie_at(year, offset)?;
+        if date < chunjie {
+            year = ChineseYear::new(year.get() - 1)?;
+            chunjie = chunjie_at(year, offset)?;
         }
-        let mut index = year as usize - 1900;
-        let ordinal = date.ordinal0();
-        let chunjie = crate::data::CHUNJIE[index] as u32;
-
-        let chinese_ordinal = if ordinal >= chunjie {
-            ordinal - chunjie
-        } else if year >= 1901 {
-            index -= 1;
-            year -= 1;
-            ordinal + crate::days_of_year(year) as u32 - CHUNJIE[index] as u32
-        } else {
-            return None;
-        } as u16;
-
-        Self::from_ordinal(ChineseYear::new(year)?, chinese_ordinal)
+        Self::from_ordinal_at(year, (date - chunjie).num_days() as u16, offset)
+    }
+    /// Converts the date of an instant, reckoned at a meridian.
+    pub fn from_datetime<Tz: TimeZone>(
+        datetime: &DateTime<Tz>, meridian: Meridian,
+    ) -> Option<Self> {
+        let offset = meridian.offset_at(datetime);
+        let date = datetime
+            .with_timezone(&FixedOffset::east_opt(offset)?)
+            .date_naive();
+        Self::from_gregorian_at(date, Some(offset))
     }
     pub fn from_ordinal(year: ChineseYear, ordinal: u16) -> Option<Self> {
+        Self::from_ordinal_at(year, ordinal, None)
+    }
+    pub(crate) fn from_ordinal_at(
+        year: ChineseYear, ordinal: u16, offset: Option<i32>,
+    ) -> Option<Self> {
         let mut month = 0u8;
         let mut day = ordinal;
-        let leap_month = leap_month(year);
-        let short_long = short_or_long(year)?;
+        let data = data(year.get(), offset)?;
+        let leap_month = data as u8 & 0x0f;
+        let short_long = short_or_long(data);
         for i in 0..=12 {
             let days_of_month = (short_long >> (12 - i) & 1) + 29;
             if day < days_of_month {
@@ -145,9 +338,12 @@ impl ChineseDate {
         self.day
     }
     pub fn ordinal(&self) -> u16 {
+        self.ordinal_at(None).unwrap()
+    }
+    fn ordinal_at(&self, offset: Option<i32>) -> Option<u16> {
         let mut ord = 0u16;
-        let leap_month = leap_month(self.year);
-        let data = data(self.year.0).unwrap();
+        let data = data(self.year.0, offset)?;
+        let leap_month = data as u8 & 0x0f;
         for i in 1..self.month.0 {
             ord += 29 + (data >> (16 - i) & 1) as u16;
             if i == leap_month {
@@ -158,30 +354,55 @@ impl ChineseDate {
             ord += 29 + (data >> (16 - self.month.0) & 1) as u16;
         }
         ord += self.day.0 as u16 - 1;
-        ord
+        Some(ord)
     }
     pub fn to_gregorian(&self) -> NaiveDate {
-        let mut ordinal = self.ordinal() + 1;
-        ordinal += CHUNJIE[self.year.0 as usize - 1900] as u16;
-        let days_of_year = crate::days_of_year(self.year.0);
-        dbg!(ordinal);
-        let year = if ordinal < days_of_year {
-            self.year.0
-        } else {
-            ordinal -= days_of_year;
-            self.year.0 + 1
-        };
-        NaiveDate::from_yo_opt(year, ordinal as u32).unwrap()
+        self.to_gregorian_at(None).unwrap()
+    }
+    /// Ganzhi of the day, following the continuous cycle of days.
+    pub fn day_ganzhi(&self) -> Ganzhi {
+        Ganzhi::of_day(&self.to_gregorian())
+    }
+    /// Converts to a Gregorian date in the calendar reckoned at a UTC offset in seconds, or by
+    /// default for `None`.
+    pub(crate) fn to_gregorian_at(self, offset: Option<i32>) -> Option<NaiveDate> {
+        chunjie_at(self.year, offset)?.checked_add_days(Days::new(self.ordinal_at(offset)? as u64))
+    }
+    /// The instant when the day begins at a meridian, in a time zone.
+    pub fn to_datetime<Tz: TimeZone>(&self, tz: &Tz, meridian: Meridian) -> Option<DateTime<Tz>> {
+        // The offset of the meridian is taken at noon of the date in Beijing time
+        let noon = self.to_gregorian_at(None)?.and_hms_opt(4, 0, 0)?.and_utc();
+        let offset = meridian.offset_at(&noon.with_timezone(tz));
+        let midnight = self.to_gregorian_at(Some(offset))?.and_hms_opt(0, 0, 0)?;
+        let start = FixedOffset::east_opt(offset)?
+            .from_local_datetime(&midnight)
+            .single()?;
+        Some(start.with_timezone(tz))
+    }
+}
+
+impl Meridian {
+    /// UTC offset of the meridian at an instant, in seconds.
+    pub fn offset_at<Tz: TimeZone>(self, datetime: &DateTime<Tz>) -> i32 {
+        match self {
+            Meridian::Beijing => BEIJING_OFFSET,
+            Meridian::Local => datetime.offset().fix().local_minus_utc(),
+            Meridian::Historical => historical_offset(datetime.naive_utc().year()),
+        }
     }
 }
 
 impl ChineseYear {
     pub fn new(year: i32) -> Option<Self> {
-        (1900..=2100).contains(&year).then_some(Self(year))
+        YEARS.contains(&year).then_some(Self(year))
     }
     pub fn get(self) -> i32 {
         self.0
     }
+    /// Ganzhi of the year, which is Jiazi in 1984.
+    pub fn ganzhi(self) -> Ganzhi {
+        Ganzhi::from_ordinal((self.0 - 1984).rem_euclid(60) as u8).unwrap()
+    }
 }
 
 impl ChineseMonth {
@@ -227,4 +448,60 @@ fn test() {
         );
         assert_eq!(chinese_date.to_gregorian(), gregorian_date);
     }
+    let new_york = FixedOffset::west_opt(5 * 3600).unwrap();
+    let datetime = new_york.with_ymd_and_hms(2023, 1, 21, 10, 0, 0).unwrap();
+    assert_eq!(
+        ChineseDate::from_datetime(&datetime, Meridian::Beijing),
+        ChineseDate::new(2022, 12, false, 30)
+    );
+    let new_year = ChineseDate::from_datetime(&datetime, Meridian::Local).unwrap();
+    assert_eq!(new_year, ChineseDate::new(2023, 1, false, 1).unwrap());
+    assert_eq!(
+        new_year.to_datetime(&new_york, Meridian::Local),
+        new_york.with_ymd_and_hms(2023, 1, 21, 0, 0, 0).single()
+    );
+    assert_eq!(
+        new_year.to_datetime(&new_york, Meridian::Beijing),
+        new_york.with_ymd_and_hms(2023, 1, 21, 11, 0, 0).single()
+    );
+    // Reckoned by the local mean time, the 3rd month of 1906 is short
+    let date = ChineseDate::new(1906, 3, false, 30).unwrap().to_gregorian();
+    let datetime = date.and_hms_opt(4, 0, 0).unwrap().and_utc();
+    assert_eq!(
+        ChineseDate::from_datetime(&datetime, Meridian::Historical),
+        ChineseDate::new(1906, 4, false, 1)
+    );
+    // Before the tables, the calendar is reckoned by the local mean time unless UTC+8 is asked for
+    let date = NaiveDate::from_ymd_opt(1896, 2, 13).unwrap();
+    assert_eq!(
+        ChineseDate::from_gregorian(&date),
+        ChineseDate::new(1896, 1, false, 1)
+    );
+    let datetime = date.and_hms_opt(4, 0, 0).unwrap().and_utc();
+    assert_eq!(
+        ChineseDate::from_datetime(&datetime, Meridian::Historical),
+        ChineseDate::new(1896, 1, false, 1)
+    );
+    assert_eq!(
+        ChineseDate::from_datetime(&datetime, Meridian::Beijing),
+        ChineseDate::new(1895, 12, false, 30)
+    );
+    for year in 1929..=2100 {
+        let index = year as usize - 1900;
+        let chunjie = NaiveDate::from_yo_opt(year, CHUNJIE[index] as u32 + 1).unwrap();
+        assert_eq!(reckon(year, BEIJING_OFFSET), Some((DATA[index], chunjie)));
+    }
+    for year in [-1000, 1000, 1899, 2101, 3000] {
+        let chunjie = chunjie(ChineseYear(year)).unwrap();
+        assert_eq!(
+            ChineseDate::from_gregorian(&chunjie),
+            ChineseDate::new(year, 1, false, 1)
+        );
+        for date in chunjie.iter_days().step_by(7).take(60) {
+            let Some(chinese_date) = ChineseDate::from_gregorian(&date) else {
+                break;
+            };
+            assert_eq!(chinese_date.to_gregorian(), date);
+        }
+    }
 }
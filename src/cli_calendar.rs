@@ -7,14 +7,10 @@ use chrono::{
 };
 
 use crate::{
-    calendar::{Calendar, Options},
-    chinese_date::ChineseDate,
-    days_of_month,
-    festivals::Festival,
-    is_weekend,
+    calendar::{Annotation, Calendar, Options},
+    days_of_month, is_weekend,
     iter::Weekdays,
-    language::{Language::*, MonthTitle, ShortTranslate, StaticTranslate, Translate, YearTitle},
-    SolarTerm,
+    language::{MonthTitle, ShortTranslate, StaticTranslate, Translate, YearTitle},
 };
 
 use Alignment::*;
@@ -39,7 +35,10 @@ pub struct Aligned<T: AsRef<str>>(pub T, pub Alignment, pub usize);
 pub fn rendered_width(s: &str) -> usize {
     s.chars()
         .map(|ch| {
-            if (0x4e00..=0x9fff).contains(&(ch as u32)) {
+            if (0x4e00..=0x9fff).contains(&(ch as u32))
+                || ('가'..='힣').contains(&ch)
+                || ('🌑'..='🌘').contains(&ch)
+            {
                 2
             } else {
                 1
@@ -51,34 +50,34 @@ pub fn rendered_width(s: &str) -> usize {
 #[derive(Clone, Copy, Debug)]
 pub struct ZipByLine<'a>(pub &'a [&'a str]);
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct WeekLine(Options);
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct TripleWeekLine(Options);
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct QuadWeekLine(Options);
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct BasicMonthCalendar(pub Calendar);
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct ListCalendar(pub Calendar);
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct MonthCalendar(pub Calendar);
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct BasicTripleCalendar(pub Calendar);
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct BasicQuadCalendar(pub Calendar);
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct TripleCalendar(pub Calendar);
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct YearCalendar {
     year: i32,
     pub today: Option<NaiveDate>,
@@ -86,8 +85,13 @@ pub struct YearCalendar {
     pub landscape: bool,
 }
 
+/// Whether days are annotated on a second line.
+fn annotated(options: &Options) -> bool {
+    options.enable_chinese || options.rokuyo || options.moon_phase || options.periods
+}
+
 fn cell_width(options: &Options) -> usize {
-    if options.enable_chinese {
+    if annotated(options) || !options.holidays.regions.is_empty() {
         CELL_WIDTH_WITH_CHINESE
     } else {
         CELL_WIDTH_WITHOUT_CHINESE
@@ -142,9 +146,9 @@ impl Display for WeekLine {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         if self.0.week_number {
             if self.0.color {
-                print!("{}", Style::new().invert());
+                write!(f, "{}", Style::new().invert())?;
             }
-            print!("    ");
+            write!(f, "    ")?;
         }
         for weekday in Weekdays(if self.0.start_on_monday { Mon } else { Sun }).take(7) {
             let centered = Aligned(
@@ -177,19 +181,19 @@ impl Display for TripleWeekLine {
             write!(
                 f,
                 "{0}{1} {0}{1} {0}",
-                WeekLine(self.0),
+                WeekLine(self.0.clone()),
                 Style::new().invert().render()
             )
         } else {
-            write!(f, "{0} {0} {0}", WeekLine(self.0))
+            write!(f, "{0} {0} {0}", WeekLine(self.0.clone()))
         }
     }
 }
 
 impl Display for QuadWeekLine {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        let triple = TripleWeekLine(self.0);
-        let single = WeekLine(self.0);
+        let triple = TripleWeekLine(self.0.clone());
+        let single = WeekLine(self.0.clone());
         if self.0.color {
             write!(f, "{}{} {}", triple, Style::new().invert().render(), single,)
         } else {
@@ -200,8 +204,8 @@ impl Display for QuadWeekLine {
 
 impl Display for BasicMonthCalendar {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        let options = self.0.options;
-        let cell_width = cell_width(&options);
+        let options = &self.0.options;
+        let cell_width = cell_width(options);
         let mut lines = 0u8;
         for (week, line) in self.0.iter() {
             if self.0.options.week_number {
@@ -213,34 +217,44 @@ impl Display for BasicMonthCalendar {
             }
             for cell in &line {
                 if let Some(cell) = cell {
+                    // The mark of a holiday or an adjusted working day takes the last 2 columns
+                    let mark = cell.arrangements().next().map(|(_, arrangement)| {
+                        Aligned(
+                            arrangement.short().translate_to_string(options.language),
+                            Left,
+                            2,
+                        )
+                        .to_string()
+                    });
+                    let day = format!("{:^cell_width$}", cell.date.day());
+                    let day = match &mark {
+                        Some(mark) => format!("{}{mark}", &day[..cell_width - 2]),
+                        None => day,
+                    };
                     if options.color {
                         let style = if cell.today {
-                            if cell.weekend {
+                            if !cell.workday {
                                 Style::new()
                                     .fg_color(Some(WEEKEND_COLOR))
                                     .bg_color(Some(WHITE))
                             } else {
                                 Style::new().invert()
                             }
-                        } else if cell.weekend {
+                        } else if !cell.workday {
                             Style::new().fg_color(Some(WEEKEND_COLOR))
                         } else {
                             Style::new()
                         };
-                        write!(
-                            f,
-                            "{}{:^cell_width$}{}",
-                            style.render(),
-                            cell.date.day(),
-                            style.render_reset(),
-                        )?;
-                    } else {
-                        #[allow(clippy::collapsible_if)]
-                        if cell.today {
-                            write!(f, "[{0:^1$}]", cell.date.day(), cell_width - 2)
-                        } else {
-                            write!(f, "{0:^1$}", cell.date.day(), cell_width)
+                        write!(f, "{}{day}{}", style.render(), style.render_reset())?;
+                    } else if cell.today {
+                        match &mark {
+                            Some(mark) => {
+                                write!(f, "[{0:^1$}{mark}]", cell.date.day(), cell_width - 4)
+                            }
+                            None => write!(f, "[{0:^1$}]", cell.date.day(), cell_width - 2),
                         }?;
+                    } else {
+                        write!(f, "{day}")?;
                     }
                 } else {
                     for _ in 0..cell_width {
@@ -249,40 +263,42 @@ impl Display for BasicMonthCalendar {
                 }
             }
             writeln!(f)?;
-            if options.enable_chinese {
+            if annotated(options) {
                 let language = options.language;
                 for cell in line {
                     if let Some(cell) = cell {
-                        let (string, color) = cell
-                            .chinese_date
-                            .map(|ch_date| {
-                                if let Some(festival) = cell.festival {
-                                    (
-                                        festival.short().translate_to_string(language),
-                                        Some(FESTIVAL_COLOR),
-                                    )
-                                } else if let Some(solar_term) = cell.solar_term {
-                                    (
-                                        solar_term.short().translate_to_string(language),
-                                        Some(SOLAR_TERM_COLOR),
-                                    )
-                                } else {
-                                    (
-                                        ch_date.short().translate_to_string(language),
-                                        (ch_date.day() == 1).then_some(NEW_MONTH_COLOR),
-                                    )
-                                }
-                            })
-                            .unwrap_or_default();
+                        let (string, color) = if let Some(annotation) = cell
+                            .annotations
+                            .iter()
+                            .find(|annotation| annotation.priority() > 0)
+                        {
+                            (
+                                annotation.short().translate_to_string(language),
+                                match annotation {
+                                    Annotation::Festival(_) => Some(FESTIVAL_COLOR),
+                                    Annotation::SolarTerm(_) => Some(SOLAR_TERM_COLOR),
+                                    _ => None,
+                                },
+                            )
+                        } else if let Some(rokuyo) = cell.rokuyo {
+                            (rokuyo.short().translate_to_string(language), None)
+                        } else if let Some(ch_date) = cell.chinese_date {
+                            (
+                                ch_date.short().translate_to_string(language),
+                                (ch_date.day() == 1).then_some(NEW_MONTH_COLOR),
+                            )
+                        } else {
+                            Default::default()
+                        };
                         if options.color {
                             let mut style = Style::new();
                             if let Some(color) = color {
                                 style = style.fg_color(Some(color))
-                            } else if cell.weekend {
+                            } else if !cell.workday {
                                 style = style.fg_color(Some(WEEKEND_COLOR))
                             };
                             if cell.today {
-                                style = if color.is_some() || cell.weekend {
+                                style = if color.is_some() || !cell.workday {
                                     style.bg_color(Some(WHITE))
                                 } else {
                                     style.invert()
@@ -314,7 +330,7 @@ impl Display for BasicMonthCalendar {
                 write!(f, " ")?;
             }
             writeln!(f)?;
-            if options.enable_chinese {
+            if annotated(options) {
                 for _ in 0..spaces {
                     write!(f, " ")?;
                 }
@@ -328,29 +344,25 @@ impl Display for BasicMonthCalendar {
 
 impl Display for MonthCalendar {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        let options = self.0.options;
+        let options = &self.0.options;
         write!(
             f,
             "{}\n{}\n{}",
             Aligned(
-                MonthTitle::from(self.0).translate_to_string(options.language),
+                self.0.title().translate_to_string(options.language),
                 Center,
-                cell_width(&options) * 7 + if self.0.options.week_number { 4 } else { 0 },
+                cell_width(options) * 7 + if self.0.options.week_number { 4 } else { 0 },
             ),
-            WeekLine(options),
-            BasicMonthCalendar(self.0),
+            WeekLine(options.clone()),
+            BasicMonthCalendar(self.0.clone()),
         )
     }
 }
 
 impl Display for ListCalendar {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        let options = self.0.options;
+        let options = &self.0.options;
         let language = options.language;
-        let today_day = self
-            .0
-            .today
-            .and_then(|today| (self.0.year() == today.year()).then(|| today.day()));
         writeln!(
             f,
             "{}:",
@@ -368,13 +380,14 @@ impl Display for ListCalendar {
                 day as u32,
             )
             .unwrap();
-            let is_today = today_day.is_some_and(|today| day == today as u8);
+            let cell = self.0.cell(date);
+            let is_today = cell.today;
+            let rest_day = !cell.workday;
             let weekday = date.weekday();
-            let weekend = is_weekend(weekday);
             let weekday_string = weekday.translate_to_string(language);
             let mut style = Style::new();
             if options.color {
-                if weekend {
+                if rest_day {
                     if is_today {
                         style = style.bg_color(Some(WHITE));
                     }
@@ -398,8 +411,16 @@ impl Display for ListCalendar {
                     Aligned(weekday_string, Left, 12),
                 )
             }?;
+            if let Some(ganzhi) = cell.day_ganzhi {
+                let width = if language.is_cjk() { 6 } else { 10 };
+                write!(
+                    f,
+                    "{}",
+                    Aligned(ganzhi.translate_to_string(language), Left, width)
+                )?;
+            }
             if options.enable_chinese {
-                if let Some(chinese_date) = ChineseDate::from_gregorian(&date) {
+                if let Some(chinese_date) = cell.chinese_date {
                     if options.color && chinese_date.day() == 1 {
                         write!(f, "{}", style.render_reset())?;
                         style = Style::new().fg_color(Some(NEW_MONTH_COLOR));
@@ -408,7 +429,7 @@ impl Display for ListCalendar {
                         }
                         write!(f, "{}", style.render())?;
                     }
-                    if language == English {
+                    if !language.is_cjk() {
                         write!(
                             f,
                             "{}, {}",
@@ -430,7 +451,7 @@ impl Display for ListCalendar {
                             )
                         )
                     }?;
-                    if let Some(festival) = Festival::from_chinese_date(chinese_date) {
+                    for festival in cell.festivals() {
                         if options.color {
                             if is_today {
                                 style = Style::new()
@@ -441,14 +462,17 @@ impl Display for ListCalendar {
                             }
                         }
                         write!(f, "  {}{}", style.render_reset(), style.render())?;
-                        if language == English {
+                        if !language.is_cjk() {
                             write!(f, "{:12}", festival.static_translate(language))
                         } else {
                             festival.translate(language, f)
                         }?;
                     }
+                    for label in &cell.events {
+                        write!(f, "  {}{label}", style.render_reset())?;
+                    }
                 }
-                if let Some(solar_term) = SolarTerm::from_date(&date) {
+                if let Some(solar_term) = cell.solar_term {
                     if options.color {
                         if is_today {
                             style = Style::new()
@@ -459,15 +483,51 @@ impl Display for ListCalendar {
                         }
                     }
                     write!(f, "  {}{}", style.render_reset(), style.render())?;
-                    if language == English {
+                    if !language.is_cjk() {
                         write!(f, "{:12}", solar_term.static_translate(language))
                     } else {
                         solar_term.translate(language, f)
                     }
                 } else {
-                    write!(f, "{:1$}", "", if language == English { 14 } else { 6 })
+                    write!(f, "{:1$}", "", if language.is_cjk() { 6 } else { 14 })
                 }?;
             }
+            if let Some(rokuyo) = cell.rokuyo {
+                if options.color {
+                    style = if is_today {
+                        Style::new().invert()
+                    } else {
+                        Style::new()
+                    };
+                }
+                write!(f, "  {}{}", style.render_reset(), style.render())?;
+                rokuyo.translate(language, f)?;
+            }
+            if let Some(moon_phase) = cell.moon_phase {
+                write!(f, "  {}{} ", style.render_reset(), moon_phase.symbol())?;
+                moon_phase.translate(language, f)?;
+            }
+            if let Some(period) = cell.period {
+                write!(f, "  {}", style.render_reset())?;
+                period.translate(language, f)?;
+            }
+            if let Some(pentad) = cell.pentad {
+                write!(f, "  {}", style.render_reset())?;
+                pentad.translate(language, f)?;
+            }
+            for (region, arrangement) in cell.arrangements() {
+                write!(
+                    f,
+                    "  {}{} ",
+                    style.render_reset(),
+                    arrangement.short().translate_adapter(language)
+                )?;
+                arrangement.translate(language, f)?;
+                // The region is told only when several are overlaid
+                if options.holidays.regions.len() > 1 {
+                    write!(f, " ({})", region.static_translate(language))?;
+                }
+            }
             if !options.color && is_today {
                 write!(f, "]")?;
             }
@@ -479,15 +539,15 @@ impl Display for ListCalendar {
 
 impl Display for BasicTripleCalendar {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        let calendar1 = self.0.succ();
-        let calendar2 = calendar1.and_then(Calendar::succ);
-        let strings = [Some(self.0), calendar1, calendar2].map(|calendar| {
+        let calendar1 = self.0.clone().succ();
+        let calendar2 = calendar1.clone().and_then(Calendar::succ);
+        let strings = [Some(self.0.clone()), calendar1, calendar2].map(|calendar| {
             calendar.map_or_else(String::new, |calendar| {
                 BasicMonthCalendar(calendar).to_string()
             })
         });
         let mut separator = String::new();
-        for _ in 0..if self.0.options.enable_chinese { 12 } else { 6 } {
+        for _ in 0..if annotated(&self.0.options) { 12 } else { 6 } {
             separator.push_str(" \n");
         }
         let strs = [strings[0].as_str(), &separator, &strings[1], &separator, &strings[2]];
@@ -497,16 +557,16 @@ impl Display for BasicTripleCalendar {
 
 impl Display for BasicQuadCalendar {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        let calendar1 = self.0.succ();
-        let calendar2 = calendar1.and_then(Calendar::succ);
-        let calendar3 = calendar2.and_then(Calendar::succ);
-        let strings = [Some(self.0), calendar1, calendar2, calendar3].map(|calendar| {
+        let calendar1 = self.0.clone().succ();
+        let calendar2 = calendar1.clone().and_then(Calendar::succ);
+        let calendar3 = calendar2.clone().and_then(Calendar::succ);
+        let strings = [Some(self.0.clone()), calendar1, calendar2, calendar3].map(|calendar| {
             calendar.map_or_else(String::new, |calendar| {
                 BasicMonthCalendar(calendar).to_string()
             })
         });
         let mut separator = String::new();
-        for _ in 0..if self.0.options.enable_chinese { 12 } else { 6 } {
+        for _ in 0..if annotated(&self.0.options) { 12 } else { 6 } {
             separator.push_str(" \n");
         }
         let strs = [
@@ -524,14 +584,14 @@ impl Display for BasicQuadCalendar {
 
 impl Display for TripleCalendar {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        let options = self.0.options;
-        let cell_width = cell_width(&options);
-        let calendar1 = self.0.succ();
-        let calendar2 = calendar1.and_then(Calendar::succ);
-        let titles = [Some(self.0), calendar1, calendar2].map(|calendar| {
+        let options = &self.0.options;
+        let cell_width = cell_width(options);
+        let calendar1 = self.0.clone().succ();
+        let calendar2 = calendar1.clone().and_then(Calendar::succ);
+        let titles = [Some(self.0.clone()), calendar1, calendar2].map(|calendar| {
             Aligned(
                 calendar.map_or_else(String::new, |calendar| {
-                    MonthTitle::from(calendar).translate_to_string(options.language)
+                    calendar.title().translate_to_string(options.language)
                 }),
                 Center,
                 cell_width * 7 + if self.0.options.week_number { 4 } else { 0 },
@@ -543,8 +603,8 @@ impl Display for TripleCalendar {
             titles[0],
             titles[1],
             titles[2],
-            TripleWeekLine(options),
-            BasicTripleCalendar(self.0),
+            TripleWeekLine(options.clone()),
+            BasicTripleCalendar(self.0.clone()),
         )
     }
 }
@@ -565,9 +625,9 @@ impl YearCalendar {
 impl Display for YearCalendar {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         use Month::*;
-        let options = self.options;
+        let options = &self.options;
         let language = options.language;
-        let cell_width = cell_width(&options);
+        let cell_width = cell_width(options);
         let month_width = cell_width * 7 + if self.options.week_number { 4 } else { 0 };
         writeln!(
             f,
@@ -598,9 +658,9 @@ impl Display for YearCalendar {
                     Aligned(month1.translate_to_string(language), Center, month_width),
                     Aligned(month2.translate_to_string(language), Center, month_width),
                     Aligned(month3.translate_to_string(language), Center, month_width),
-                    QuadWeekLine(options),
+                    QuadWeekLine(options.clone()),
                     BasicQuadCalendar(
-                        Calendar::new(self.year, month, self.today, options).unwrap()
+                        Calendar::new(self.year, month, self.today, options.clone()).unwrap()
                     )
                 )?;
             }
@@ -614,9 +674,9 @@ impl Display for YearCalendar {
                     Aligned(month.translate_to_string(language), Center, month_width),
                     Aligned(month1.translate_to_string(language), Center, month_width),
                     Aligned(month2.translate_to_string(language), Center, month_width),
-                    TripleWeekLine(options),
+                    TripleWeekLine(options.clone()),
                     BasicTripleCalendar(
-                        Calendar::new(self.year, month, self.today, options).unwrap()
+                        Calendar::new(self.year, month, self.today, options.clone()).unwrap()
                     )
                 )?;
             }
@@ -0,0 +1,233 @@
+//! Recurring events defined by users on lunar dates, as memorial days, with the policies for leap
+//! months and for the 30th day in short months.
+
+use chrono::NaiveDate;
+
+use crate::{
+    chinese_date::{days_of_chinese_month_at, leap_month_at, ChineseMonth, ChineseYear},
+    ChineseDate,
+};
+
+/// Day of the month an event recurs on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventDay {
+    /// A day from 1 to 30.
+    Day(u8),
+    /// The last day, which is the 29th or the 30th.
+    Last,
+}
+
+/// Months an event is observed in when its month is followed by a leap month.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LeapPolicy {
+    /// The regular month only.
+    #[default]
+    Regular,
+    /// The leap month only, or the regular month in years without it.
+    Leap,
+    /// Both months.
+    Both,
+}
+
+/// What becomes of an event on the 30th day in a month of 29 days.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Day30Policy {
+    /// Observed on the 29th day instead.
+    #[default]
+    MoveTo29,
+    /// Not observed in the month.
+    Skip,
+}
+
+/// An event recurring every year on a lunar date, as "3/15" or "12/last day".
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Event {
+    pub label: String,
+    pub month: u8,
+    pub day: EventDay,
+    pub leap_policy: LeapPolicy,
+    pub day_30_policy: Day30Policy,
+}
+
+/// A set of events resolved together.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Registry {
+    events: Vec<Event>,
+}
+
+/// The dates of a lunar month and day in a Chinese year under the policies, in the calendar
+/// reckoned at a UTC offset in seconds, or by default for `None`.
+pub(crate) fn resolve(
+    year: ChineseYear, month: u8, day: EventDay, leap_policy: LeapPolicy,
+    day_30_policy: Day30Policy, offset: Option<i32>,
+) -> Vec<ChineseDate> {
+    let has_leap = leap_month_at(year, offset) == month;
+    let leaps: &[bool] = match leap_policy {
+        LeapPolicy::Both if has_leap => &[false, true],
+        LeapPolicy::Leap if has_leap => &[true],
+        _ => &[false],
+    };
+    leaps
+        .iter()
+        .filter_map(|&leap| {
+            let days = days_of_chinese_month_at(year, ChineseMonth::new(month, leap)?, offset)?;
+            let day = match day {
+                EventDay::Last => days,
+                EventDay::Day(day) if day <= days => day,
+                EventDay::Day(_) => match day_30_policy {
+                    Day30Policy::MoveTo29 => days,
+                    Day30Policy::Skip => return None,
+                },
+            };
+            ChineseDate::new(year.get(), month, leap, day)
+        })
+        .collect()
+}
+
+impl Event {
+    /// An event observed in the regular month and moved to the 29th day in short months.
+    pub fn new(label: impl Into<String>, month: u8, day: EventDay) -> Option<Self> {
+        if !(1..=12).contains(&month)
+            || matches!(day, EventDay::Day(day) if !(1..=30).contains(&day))
+        {
+            return None;
+        }
+        Some(Self {
+            label: label.into(),
+            month,
+            day,
+            leap_policy: LeapPolicy::default(),
+            day_30_policy: Day30Policy::default(),
+        })
+    }
+    /// The dates of the event in a Chinese year, at most 2 for [`LeapPolicy::Both`].
+    pub fn dates(&self, year: ChineseYear) -> Vec<ChineseDate> {
+        self.dates_at(year, None)
+    }
+    fn dates_at(&self, year: ChineseYear, offset: Option<i32>) -> Vec<ChineseDate> {
+        resolve(
+            year,
+            self.month,
+            self.day,
+            self.leap_policy,
+            self.day_30_policy,
+            offset,
+        )
+    }
+    /// The Gregorian dates of the event in a Chinese year.
+    pub fn occurrences(&self, year: ChineseYear) -> Vec<NaiveDate> {
+        self.dates(year)
+            .iter()
+            .map(ChineseDate::to_gregorian)
+            .collect()
+    }
+    /// Whether the event falls on a date.
+    pub fn falls_on(&self, date: &ChineseDate) -> bool {
+        self.falls_on_at(date, None)
+    }
+    fn falls_on_at(&self, date: &ChineseDate, offset: Option<i32>) -> bool {
+        self.dates_at(date.chinese_year(), offset).contains(date)
+    }
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn add(&mut self, event: Event) {
+        self.events.push(event);
+    }
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+    /// The events on a Gregorian date.
+    pub fn on(&self, date: NaiveDate) -> impl Iterator<Item = &Event> {
+        self.on_at(date, None)
+    }
+    /// The events on a Gregorian date in the calendar reckoned at a UTC offset in seconds, or by
+    /// default for `None`.
+    pub(crate) fn on_at(
+        &self, date: NaiveDate, offset: Option<i32>,
+    ) -> impl Iterator<Item = &Event> {
+        let chinese_date = ChineseDate::from_gregorian_at(date, offset);
+        self.events.iter().filter(move |event| {
+            chinese_date
+                .as_ref()
+                .is_some_and(|chinese_date| event.falls_on_at(chinese_date, offset))
+        })
+    }
+    /// Every occurrence of the events in a Chinese year, in order of date.
+    pub fn occurrences(&self, year: ChineseYear) -> Vec<(NaiveDate, &Event)> {
+        let mut occurrences: Vec<_> = self
+            .events
+            .iter()
+            .flat_map(|event| {
+                event
+                    .occurrences(year)
+                    .into_iter()
+                    .map(move |date| (date, event))
+            })
+            .collect();
+        occurrences.sort_by_key(|(date, _)| *date);
+        occurrences
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    use crate::date;
+    let year = |year| ChineseYear::new(year).unwrap();
+
+    assert_eq!(Event::new("", 13, EventDay::Day(1)), None);
+    assert_eq!(Event::new("", 1, EventDay::Day(31)), None);
+
+    // 2023 has a leap 2nd month
+    let event = Event::new("Memorial", 2, EventDay::Day(15)).unwrap();
+    assert_eq!(event.occurrences(year(2023)), [date(2023, 3, 6)]);
+    let event = Event {
+        leap_policy: LeapPolicy::Leap,
+        ..event
+    };
+    assert_eq!(event.occurrences(year(2023)), [date(2023, 4, 5)]);
+    assert_eq!(event.occurrences(year(2024)), [date(2024, 3, 24)]);
+    let event = Event {
+        leap_policy: LeapPolicy::Both,
+        ..event
+    };
+    assert_eq!(
+        event.occurrences(year(2023)),
+        [date(2023, 3, 6), date(2023, 4, 5)]
+    );
+
+    // The 12th month of 2023 has 30 days, and that of 2024 has 29
+    let event = Event::new("Year end", 12, EventDay::Last).unwrap();
+    assert_eq!(event.occurrences(year(2023)), [date(2024, 2, 9)]);
+    assert_eq!(event.occurrences(year(2024)), [date(2025, 1, 28)]);
+    let event = Event::new("Year end", 12, EventDay::Day(30)).unwrap();
+    assert_eq!(event.occurrences(year(2024)), [date(2025, 1, 28)]);
+    let event = Event {
+        day_30_policy: Day30Policy::Skip,
+        ..event
+    };
+    assert_eq!(event.occurrences(year(2024)), []);
+
+    let mut registry = Registry::new();
+    registry.add(event);
+    registry.add(Event::new("Founding", 3, EventDay::Day(15)).unwrap());
+    assert_eq!(
+        registry
+            .on(date(2024, 2, 9))
+            .map(|event| event.label.as_str())
+            .collect::<Vec<_>>(),
+        ["Year end"]
+    );
+    assert_eq!(
+        registry
+            .occurrences(year(2023))
+            .iter()
+            .map(|(date, _)| *date)
+            .collect::<Vec<_>>(),
+        [date(2023, 5, 4), date(2024, 2, 9)]
+    );
+}
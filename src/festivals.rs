@@ -1,24 +1,165 @@
 use crate::{
+    calendar::Variant,
     chinese_date::days_of_chinese_month,
+    japanese_date::JapaneseDate,
+    korean_date::KoreanDate,
     language::{Language, ShortTranslate, StaticTranslate, Translate},
-    ChineseDate,
+    vietnamese_date::VietnameseDate,
+    ChineseDate, SolarTerm,
 };
+use chrono::{Datelike, Days, NaiveDate};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 #[non_exhaustive]
 pub enum Festival {
     Chunjie,
+    Powu,
+    Renri,
+    Tiangongdan,
     Yuanxiaojie,
+    Longtaitou,
+    Huazhaojie,
+    Guanyindan,
+    Shangsijie,
+    Mazudan,
+    Hanshijie,
+    Qingmingjie,
+    Fodan,
     Duanwujie,
+    Tiankuangjie,
+    Qixi,
+    Zhongyuanjie,
     Zhongqiujie,
+    Chongyangjie,
+    Hanyijie,
+    Xiayuanjie,
+    Dongzhijie,
+    Labajie,
     NorthernXiaonian,
     SouthernXiaonian,
     Chuxi,
+    GioToHungVuong,
+}
+
+/// How widely and by whom a festival is kept.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Category {
+    pub major: bool,
+    pub tradition: Option<Tradition>,
+    /// Whether the festival is kept only in some regions, as Mazudan along the southeast coast.
+    pub regional: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tradition {
+    Taoist,
+    Buddhist,
 }
 
 impl Festival {
+    pub fn category(self) -> Category {
+        use Festival::*;
+        let major = matches!(
+            self,
+            Chunjie
+                | Yuanxiaojie
+                | Qingmingjie
+                | Duanwujie
+                | Qixi
+                | Zhongqiujie
+                | Chongyangjie
+                | Chuxi
+        );
+        let tradition = match self {
+            Tiangongdan | Mazudan | Zhongyuanjie | Xiayuanjie => Some(Tradition::Taoist),
+            Guanyindan | Fodan | Labajie => Some(Tradition::Buddhist),
+            _ => None,
+        };
+        let regional = matches!(
+            self,
+            Powu | Tiangongdan
+                | Huazhaojie
+                | Mazudan
+                | NorthernXiaonian
+                | SouthernXiaonian
+                | GioToHungVuong
+        );
+        Category {
+            major,
+            tradition,
+            regional,
+        }
+    }
     pub fn from_chinese_date(date: ChineseDate) -> Option<Self> {
+        Self::from_lunar_date(date, || {
+            days_of_chinese_month(date.chinese_year(), date.chinese_month()).unwrap()
+        })
+    }
+    /// Festivals of the Vietnamese calendar, where Chunjie is Tết Nguyên Đán and the Xiaonians
+    /// are not kept.
+    pub fn from_vietnamese_date(date: VietnameseDate) -> Option<Self> {
+        use Festival::*;
+        let lunar_date = date.0;
+        if !lunar_date.leap() && lunar_date.month() == 3 && lunar_date.day() == 10 {
+            return Some(GioToHungVuong);
+        }
+        Self::from_lunar_date(lunar_date, || date.days_of_month())
+            .filter(|festival| !matches!(festival, NorthernXiaonian | SouthernXiaonian))
+    }
+    /// Festivals of the Korean calendar, where Chunjie is Seollal and Zhongqiujie is Chuseok.
+    pub fn from_korean_date(date: KoreanDate) -> Option<Self> {
+        use Festival::*;
+        Self::from_lunar_date(date.0, || date.days_of_month())
+            .filter(|festival| matches!(festival, Chunjie | Zhongqiujie))
+    }
+    /// Festivals of the Japanese old calendar, of which only the moon-viewing night (十五夜) is
+    /// widely kept.
+    pub fn from_japanese_date(date: JapaneseDate) -> Option<Self> {
+        Self::from_lunar_date(date.0, || date.days_of_month())
+            .filter(|festival| *festival == Festival::Zhongqiujie)
+    }
+    /// Festivals beginning with a solar term.
+    pub fn from_solar_term(solar_term: SolarTerm) -> Option<Self> {
+        match solar_term {
+            SolarTerm::Qingming => Some(Festival::Qingmingjie),
+            SolarTerm::Dongzhi => Some(Festival::Dongzhijie),
+            _ => None,
+        }
+    }
+    /// Festivals of a Gregorian date defined by solar terms, reckoned at the offset of a variant.
+    /// Hanshijie is the day before Qingming, but in the Korean calendar, which keeps Hanshijie
+    /// (한식) and Dongzhijie, it is the 105th day after Dongzhi. The Japanese old calendar keeps
+    /// none of them.
+    pub fn from_gregorian_date(date: &impl Datelike, variant: Variant) -> Option<Self> {
+        use Festival::*;
+        let date = NaiveDate::from_ymd_opt(date.year(), date.month(), date.day())?;
+        let offset = variant.offset();
+        let festival = SolarTerm::from_date_at(date, offset)
+            .and_then(Self::from_solar_term)
+            .or_else(|| {
+                // Both reckonings fall in early April
+                if date.month() != 4 {
+                    return None;
+                }
+                let hanshijie = match variant {
+                    Variant::Korean => SolarTerm::Dongzhi
+                        .date_at(date.year() - 1, offset)?
+                        .checked_add_days(Days::new(105))?,
+                    _ => SolarTerm::Qingming
+                        .date_at(date.year(), offset)?
+                        .pred_opt()?,
+                };
+                (date == hanshijie).then_some(Hanshijie)
+            })?;
+        match variant {
+            Variant::Chinese => Some(festival),
+            Variant::Vietnamese => (festival != Hanshijie).then_some(festival),
+            Variant::Korean => (festival != Qingmingjie).then_some(festival),
+            Variant::Japanese => None,
+        }
+    }
+    fn from_lunar_date(date: ChineseDate, days_of_month: impl FnOnce() -> u8) -> Option<Self> {
         use Festival::*;
         if date.leap() {
             return None;
@@ -26,17 +167,43 @@ impl Festival {
         match date.month() {
             1 => match date.day() {
                 1 => Some(Chunjie),
+                5 => Some(Powu),
+                7 => Some(Renri),
+                9 => Some(Tiangongdan),
                 15 => Some(Yuanxiaojie),
                 _ => None,
             },
+            2 => match date.day() {
+                2 => Some(Longtaitou),
+                12 => Some(Huazhaojie),
+                19 => Some(Guanyindan),
+                _ => None,
+            },
+            3 => match date.day() {
+                3 => Some(Shangsijie),
+                23 => Some(Mazudan),
+                _ => None,
+            },
+            4 => (date.day() == 8).then_some(Fodan),
             5 => (date.day() == 5).then_some(Duanwujie),
+            6 => (date.day() == 6).then_some(Tiankuangjie),
+            7 => match date.day() {
+                7 => Some(Qixi),
+                15 => Some(Zhongyuanjie),
+                _ => None,
+            },
             8 => (date.day() == 15).then_some(Zhongqiujie),
+            9 => (date.day() == 9).then_some(Chongyangjie),
+            10 => match date.day() {
+                1 => Some(Hanyijie),
+                15 => Some(Xiayuanjie),
+                _ => None,
+            },
             12 => match date.day() {
+                8 => Some(Labajie),
                 23 => Some(NorthernXiaonian),
                 24 => Some(SouthernXiaonian),
-                other => (other
-                    == days_of_chinese_month(date.chinese_year(), date.chinese_month()).unwrap())
-                .then_some(Chuxi),
+                other => (other == days_of_month()).then_some(Chuxi),
             },
             _ => None,
         }
@@ -50,30 +217,177 @@ impl StaticTranslate for Festival {
         match language {
             English => match self {
                 Chunjie => "Chunjie",
+                Powu => "Powu",
+                Renri => "Renri",
+                Tiangongdan => "Tiangongdan",
                 Yuanxiaojie => "Yuanxiaojie",
+                Longtaitou => "Longtaitou",
+                Huazhaojie => "Huazhaojie",
+                Guanyindan => "Guanyindan",
+                Shangsijie => "Shangsijie",
+                Mazudan => "Mazudan",
+                Hanshijie => "Hanshijie",
+                Qingmingjie => "Qingmingjie",
+                Fodan => "Fodan",
                 Duanwujie => "Duanwujie",
+                Tiankuangjie => "Tiankuangjie",
+                Qixi => "Qixi",
+                Zhongyuanjie => "Zhongyuanjie",
                 Zhongqiujie => "Zhongqiujie",
+                Chongyangjie => "Chongyangjie",
+                Hanyijie => "Hanyijie",
+                Xiayuanjie => "Xiayuanjie",
+                Dongzhijie => "Dongzhijie",
+                Labajie => "Labajie",
                 NorthernXiaonian => "Northern Xiaonian",
                 SouthernXiaonian => "Southern Xiaonian",
                 Chuxi => "Chuxi",
+                GioToHungVuong => "Gio To Hung Vuong",
             },
             ChineseSimplified => match self {
                 Chunjie => "春节",
+                Powu => "破五",
+                Renri => "人日",
+                Tiangongdan => "天公诞",
                 Yuanxiaojie => "元宵节",
+                Longtaitou => "龙抬头",
+                Huazhaojie => "花朝节",
+                Guanyindan => "观音诞",
+                Shangsijie => "上巳节",
+                Mazudan => "妈祖诞",
+                Hanshijie => "寒食节",
+                Qingmingjie => "清明节",
+                Fodan => "佛诞",
                 Duanwujie => "端午节",
+                Tiankuangjie => "天贶节",
+                Qixi => "七夕",
+                Zhongyuanjie => "中元节",
                 Zhongqiujie => "中秋节",
+                Chongyangjie => "重阳节",
+                Hanyijie => "寒衣节",
+                Xiayuanjie => "下元节",
+                Dongzhijie => "冬至节",
+                Labajie => "腊八节",
                 NorthernXiaonian => "北方小年",
                 SouthernXiaonian => "南方小年",
                 Chuxi => "除夕",
+                GioToHungVuong => "雄王祭",
             },
             ChineseTraditional => match self {
                 Chunjie => "春節",
+                Powu => "破五",
+                Renri => "人日",
+                Tiangongdan => "天公誕",
                 Yuanxiaojie => "元宵節",
+                Longtaitou => "龍抬頭",
+                Huazhaojie => "花朝節",
+                Guanyindan => "觀音誕",
+                Shangsijie => "上巳節",
+                Mazudan => "媽祖誕",
+                Hanshijie => "寒食節",
+                Qingmingjie => "清明節",
+                Fodan => "佛誕",
                 Duanwujie => "端午節",
+                Tiankuangjie => "天貺節",
+                Qixi => "七夕",
+                Zhongyuanjie => "中元節",
                 Zhongqiujie => "中秋節",
+                Chongyangjie => "重陽節",
+                Hanyijie => "寒衣節",
+                Xiayuanjie => "下元節",
+                Dongzhijie => "冬至節",
+                Labajie => "臘八節",
                 NorthernXiaonian => "北方小年",
                 SouthernXiaonian => "南方小年",
                 Chuxi => "除夕",
+                GioToHungVuong => "雄王祭",
+            },
+            Vietnamese => match self {
+                Chunjie => "Tết Nguyên Đán",
+                Powu => "Phá ngũ",
+                Renri => "Nhân nhật",
+                Tiangongdan => "Vía Ngọc Hoàng",
+                Yuanxiaojie => "Tết Nguyên Tiêu",
+                Longtaitou => "Long đài đầu",
+                Huazhaojie => "Tết Hoa triêu",
+                Guanyindan => "Vía Quan Âm",
+                Shangsijie => "Tết Thượng tị",
+                Mazudan => "Vía Thiên Hậu",
+                Hanshijie => "Tết Hàn thực",
+                Qingmingjie => "Tết Thanh minh",
+                Fodan => "Phật đản",
+                Duanwujie => "Tết Đoan Ngọ",
+                Tiankuangjie => "Tết Thiên huống",
+                Qixi => "Thất tịch",
+                Zhongyuanjie => "Tết Trung Nguyên",
+                Zhongqiujie => "Tết Trung Thu",
+                Chongyangjie => "Tết Trùng Cửu",
+                Hanyijie => "Tết Hàn y",
+                Xiayuanjie => "Tết Hạ Nguyên",
+                Dongzhijie => "Tết Đông chí",
+                Labajie => "Lạp bát",
+                NorthernXiaonian => "Tiểu niên (Bắc)",
+                SouthernXiaonian => "Tiểu niên (Nam)",
+                Chuxi => "Giao thừa",
+                GioToHungVuong => "Giỗ Tổ Hùng Vương",
+            },
+            Korean => match self {
+                Chunjie => "설날",
+                Powu => "파오",
+                Renri => "인일",
+                Tiangongdan => "천공탄",
+                Yuanxiaojie => "정월 대보름",
+                Longtaitou => "용대두",
+                Huazhaojie => "화조절",
+                Guanyindan => "관음탄",
+                Shangsijie => "삼짇날",
+                Mazudan => "마조탄",
+                Hanshijie => "한식",
+                Qingmingjie => "청명",
+                Fodan => "부처님 오신 날",
+                Duanwujie => "단오",
+                Tiankuangjie => "천황절",
+                Qixi => "칠석",
+                Zhongyuanjie => "백중",
+                Zhongqiujie => "추석",
+                Chongyangjie => "중양절",
+                Hanyijie => "한의절",
+                Xiayuanjie => "하원",
+                Dongzhijie => "동지",
+                Labajie => "납팔",
+                NorthernXiaonian => "북방 소년",
+                SouthernXiaonian => "남방 소년",
+                Chuxi => "섣달 그믐",
+                GioToHungVuong => "웅왕제",
+            },
+            Japanese => match self {
+                Chunjie => "旧正月",
+                Powu => "破五",
+                Renri => "人日",
+                Tiangongdan => "天公誕",
+                Yuanxiaojie => "小正月",
+                Longtaitou => "龍抬頭",
+                Huazhaojie => "花朝節",
+                Guanyindan => "観音誕",
+                Shangsijie => "上巳",
+                Mazudan => "媽祖誕",
+                Hanshijie => "寒食",
+                Qingmingjie => "清明",
+                Fodan => "灌仏会",
+                Duanwujie => "端午",
+                Tiankuangjie => "天貺節",
+                Qixi => "七夕",
+                Zhongyuanjie => "中元",
+                Zhongqiujie => "十五夜",
+                Chongyangjie => "重陽",
+                Hanyijie => "寒衣節",
+                Xiayuanjie => "下元",
+                Dongzhijie => "冬至",
+                Labajie => "臘八",
+                NorthernXiaonian => "北方小年",
+                SouthernXiaonian => "南方小年",
+                Chuxi => "大晦日",
+                GioToHungVuong => "雄王祭",
             },
         }
     }
@@ -87,19 +401,161 @@ impl Translate for Festival {
 
 impl ShortTranslate for Festival {
     fn short_translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        use Festival::*;
         let translation = self.static_translate(language);
-        if language == Language::English {
-            if translation.len() > 6 {
-                write!(f, "{}.", &translation[..5])
+        if language == Language::Vietnamese {
+            match self {
+                Chunjie => "Tết",
+                Powu => "P.ngũ",
+                Renri => "N.nhật",
+                Tiangongdan => "Vía NH",
+                Yuanxiaojie => "Rằm T1",
+                Longtaitou => "Long.Đ",
+                Huazhaojie => "H.triêu",
+                Guanyindan => "Vía QÂ",
+                Shangsijie => "T.tị",
+                Mazudan => "Vía TH",
+                Hanshijie => "H.thực",
+                Qingmingjie => "T.minh",
+                Fodan => "P.đản",
+                Duanwujie => "Đ.Ngọ",
+                Tiankuangjie => "T.huống",
+                Qixi => "T.tịch",
+                Zhongyuanjie => "T.Ng.",
+                Zhongqiujie => "T.Thu",
+                Chongyangjie => "T.Cửu",
+                Hanyijie => "Hàn y",
+                Xiayuanjie => "H.Ng.",
+                Dongzhijie => "Đ.chí",
+                Labajie => "Lạp bát",
+                NorthernXiaonian => "T.niên",
+                SouthernXiaonian => "T.niên",
+                Chuxi => "G.thừa",
+                GioToHungVuong => "Giỗ Tổ",
+            }
+            .fmt(f)
+        } else if language == Language::English {
+            if translation.chars().count() > 6 {
+                write!(f, "{}.", prefix(translation, 5))
             } else {
                 translation.fmt(f)
             }
         } else {
-            if translation.len() > 9 {
-                write!(f, "{}..", &translation[..6])
+            if translation.chars().count() > 3 {
+                write!(f, "{}..", prefix(translation, 2))
             } else {
                 translation.fmt(f)
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// The first characters of a string, cut between characters rather than bytes.
+fn prefix(s: &str, chars: usize) -> &str {
+    s.char_indices()
+        .nth(chars)
+        .map_or(s, |(index, _)| &s[..index])
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    use Language::*;
+
+    let festival =
+        |month, day| Festival::from_chinese_date(ChineseDate::new(2024, month, false, day)?);
+    assert_eq!(festival(2, 2), Some(Festival::Longtaitou));
+    assert_eq!(festival(3, 3), Some(Festival::Shangsijie));
+    assert_eq!(festival(7, 7), Some(Festival::Qixi));
+    assert_eq!(festival(7, 15), Some(Festival::Zhongyuanjie));
+    assert_eq!(festival(9, 9), Some(Festival::Chongyangjie));
+    assert_eq!(festival(10, 1), Some(Festival::Hanyijie));
+    assert_eq!(festival(10, 15), Some(Festival::Xiayuanjie));
+    assert_eq!(festival(12, 8), Some(Festival::Labajie));
+    assert_eq!(festival(9, 10), None);
+    assert_eq!(
+        Festival::from_chinese_date(ChineseDate::new(2023, 2, true, 2).unwrap()),
+        None
+    );
+
+    let category = Festival::Qixi.category();
+    assert!(category.major && !category.regional);
+    assert_eq!(category.tradition, None);
+    assert_eq!(
+        Festival::Zhongyuanjie.category().tradition,
+        Some(Tradition::Taoist)
+    );
+    assert_eq!(
+        Festival::Labajie.category().tradition,
+        Some(Tradition::Buddhist)
+    );
+    assert!(Festival::Mazudan.category().regional);
+
+    // Qingming of 2024 is on 04-04, and Dongzhi on 12-21
+    let date = |month, day| NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+    assert_eq!(
+        Festival::from_gregorian_date(&date(4, 3), Variant::Chinese),
+        Some(Festival::Hanshijie)
+    );
+    assert_eq!(
+        Festival::from_gregorian_date(&date(4, 4), Variant::Chinese),
+        Some(Festival::Qingmingjie)
+    );
+    // Dongzhi of 2023 is on 12-22 at UTC+9, 105 days before Hansik
+    assert_eq!(
+        Festival::from_gregorian_date(&date(4, 3), Variant::Korean),
+        None
+    );
+    assert_eq!(
+        Festival::from_gregorian_date(&date(4, 4), Variant::Korean),
+        None
+    );
+    assert_eq!(
+        Festival::from_gregorian_date(&date(4, 5), Variant::Korean),
+        Some(Festival::Hanshijie)
+    );
+    assert_eq!(
+        Festival::from_gregorian_date(&date(12, 21), Variant::Vietnamese),
+        Some(Festival::Dongzhijie)
+    );
+    assert_eq!(
+        Festival::from_gregorian_date(&date(12, 21), Variant::Japanese),
+        None
+    );
+    assert_eq!(
+        Festival::from_gregorian_date(&date(4, 5), Variant::Chinese),
+        None
+    );
+
+    assert_eq!(
+        Festival::Chongyangjie.translate_to_string(ChineseTraditional),
+        "重陽節"
+    );
+    assert_eq!(
+        Festival::Longtaitou
+            .short()
+            .translate_to_string(ChineseSimplified),
+        "龙抬头"
+    );
+    assert_eq!(
+        Festival::Chongyangjie.short().translate_to_string(English),
+        "Chong."
+    );
+    assert_eq!(
+        Festival::Fodan.short().translate_to_string(Korean),
+        "부처.."
+    );
+    assert_eq!(prefix("Tết Hàn thực", 5), "Tết H");
+    for festival in [Festival::Hanshijie, Festival::GioToHungVuong, Festival::Zhongyuanjie] {
+        for &language in Language::ALL {
+            assert!(
+                festival
+                    .short()
+                    .translate_to_string(language)
+                    .chars()
+                    .count()
+                    <= 6
+            );
+        }
+    }
+}
@@ -0,0 +1,167 @@
+//! The Four Pillars (四柱, or 八字), the ganzhi of the year, month, day and hour of an instant.
+//!
+//! The year begins at Lichun and each month at a sectional term (节), reckoned at the exact
+//! instant of the term; the day and the hour are read from the clock of a [`Meridian`].
+
+use chrono::{DateTime, Datelike, FixedOffset, TimeDelta, TimeZone, Timelike, Utc};
+use std::fmt::{Formatter, Result as FmtResult};
+
+use crate::{
+    chinese_date::{ChineseYear, Meridian},
+    ganzhi::{Dizhi, Ganzhi, Tiangan},
+    language::{Language, Translate},
+    SolarTerm,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FourPillars {
+    pub year: Ganzhi,
+    pub month: Ganzhi,
+    pub day: Ganzhi,
+    pub hour: Ganzhi,
+}
+
+/// Where the day pillar changes, as schools differ on the zi hour (23:00 to 01:00).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DayStart {
+    /// The day begins with the zi hour at 23:00.
+    #[default]
+    ZiHour,
+    /// The day begins at midnight, so the late zi hour (晚子时) from 23:00 keeps the day pillar of
+    /// the day it ends, while its hour pillar is the zi hour of the following day.
+    Midnight,
+}
+
+impl FourPillars {
+    pub fn from_datetime<Tz: TimeZone>(
+        datetime: &DateTime<Tz>, meridian: Meridian, day_start: DayStart,
+    ) -> Option<Self> {
+        let instant = datetime.with_timezone(&Utc);
+        let (term_year, term) = sectional_term(&instant)?;
+
+        // Xiaohan, the only sectional term before Lichun, still belongs to the previous year
+        let year = if term == SolarTerm::Xiaohan {
+            term_year - 1
+        } else {
+            term_year
+        };
+        let year = ChineseYear(year).ganzhi();
+
+        // The months from Lichun are Yin, Mao, ..., and the stem of Yin follows the year stem
+        let dizhi = Dizhi::Yin + (term.as_ordinal() as i32 / 2 - 1);
+        let yin_tiangan = Tiangan::Bing + year.tiangan().as_ordinal() as i32 * 2;
+        let month = Ganzhi::new(yin_tiangan + (dizhi - Dizhi::Yin), dizhi)?;
+
+        let local = datetime
+            .with_timezone(&FixedOffset::east_opt(meridian.offset_at(datetime))?)
+            .naive_local();
+        let date = local.date();
+        // The zi hour from 23:00 starts the cycle of hours of the next day
+        let zi_date = if local.hour() == 23 {
+            date.checked_add_signed(TimeDelta::days(1))?
+        } else {
+            date
+        };
+        let day = match day_start {
+            DayStart::ZiHour => Ganzhi::of_day(&zi_date),
+            DayStart::Midnight => Ganzhi::of_day(&date),
+        };
+
+        let dizhi = Dizhi::Zi + ((local.hour() as i32 + 1) / 2);
+        let zi_tiangan = Tiangan::Jia + Ganzhi::of_day(&zi_date).tiangan().as_ordinal() as i32 * 2;
+        let hour = Ganzhi::new(zi_tiangan + (dizhi - Dizhi::Zi), dizhi)?;
+
+        Some(Self {
+            year,
+            month,
+            day,
+            hour,
+        })
+    }
+}
+
+/// The latest sectional term not after an instant, with the Gregorian year it falls in.
+fn sectional_term(instant: &DateTime<Utc>) -> Option<(i32, SolarTerm)> {
+    // Every month holds exactly one sectional term, starting from Xiaohan in January
+    let (year, month0) = (instant.year(), instant.month0() as u8);
+    let term = SolarTerm::from_ordinal(month0 * 2)?;
+    if term.instant_in(year, &Utc)? <= *instant {
+        Some((year, term))
+    } else if month0 == 0 {
+        Some((year - 1, SolarTerm::Daxue))
+    } else {
+        Some((year, SolarTerm::from_ordinal(month0 * 2 - 2)?))
+    }
+}
+
+impl Translate for FourPillars {
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        let [year, month, day, hour] =
+            [self.year, self.month, self.day, self.hour].map(|g| g.translate_to_string(language));
+        match language {
+            Language::English => write!(f, "{year} year, {month} month, {day} day, {hour} hour"),
+            Language::ChineseSimplified => write!(f, "{year}年 {month}月 {day}日 {hour}时"),
+            Language::ChineseTraditional | Language::Japanese => {
+                write!(f, "{year}年 {month}月 {day}日 {hour}時")
+            }
+            Language::Vietnamese => write!(f, "Năm {year}, tháng {month}, ngày {day}, giờ {hour}"),
+            Language::Korean => write!(f, "{year}년 {month}월 {day}일 {hour}시"),
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    use Language::*;
+
+    let beijing = FixedOffset::east_opt(8 * 3600).unwrap();
+    let pillars = |(y, m, d, h, min), day_start| {
+        let datetime = beijing.with_ymd_and_hms(y, m, d, h, min, 0).unwrap();
+        FourPillars::from_datetime(&datetime, Meridian::Beijing, day_start)
+            .unwrap()
+            .translate_to_string(ChineseSimplified)
+    };
+
+    // Lichun of 2024 begins at 16:27 on 02-04 in Beijing time
+    assert_eq!(
+        pillars((2024, 2, 4, 16, 26), DayStart::ZiHour),
+        "癸卯年 乙丑月 戊戌日 庚申时"
+    );
+    assert_eq!(
+        pillars((2024, 2, 4, 16, 28), DayStart::ZiHour),
+        "甲辰年 丙寅月 戊戌日 庚申时"
+    );
+    assert_eq!(
+        pillars((2024, 2, 4, 23, 30), DayStart::ZiHour),
+        "甲辰年 丙寅月 己亥日 甲子时"
+    );
+    assert_eq!(
+        pillars((2024, 2, 4, 23, 30), DayStart::Midnight),
+        "甲辰年 丙寅月 戊戌日 甲子时"
+    );
+    assert_eq!(
+        pillars((2024, 2, 5, 0, 30), DayStart::Midnight),
+        "甲辰年 丙寅月 己亥日 甲子时"
+    );
+    // Before Xiaohan, the month is still Zi of the previous Gregorian year
+    assert_eq!(
+        pillars((2025, 1, 1, 12, 0), DayStart::ZiHour),
+        "甲辰年 丙子月 庚午日 壬午时"
+    );
+
+    // The clock follows the meridian, while the terms are reckoned at their exact instants
+    let utc = Utc.with_ymd_and_hms(2024, 2, 4, 15, 30, 0).unwrap();
+    let local = FourPillars::from_datetime(&utc, Meridian::Local, DayStart::ZiHour).unwrap();
+    let beijing = FourPillars::from_datetime(&utc, Meridian::Beijing, DayStart::ZiHour).unwrap();
+    assert_eq!(local.year, beijing.year);
+    assert_eq!(local.day, beijing.day - 1);
+    assert_eq!(
+        local.translate_to_string(English),
+        "Jiachen year, Bingyin month, Wuxu day, Gengshen hour"
+    );
+    assert_eq!(
+        beijing.translate_to_string(Vietnamese),
+        "Năm Giáp Thìn, tháng Bính Dần, ngày Kỷ Hợi, giờ Giáp Tý"
+    );
+}
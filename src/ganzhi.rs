@@ -0,0 +1,339 @@
+//! The heavenly stems (天干), earthly branches (地支) and the sexagenary cycle (干支) they form.
+
+use chrono::Datelike;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+use crate::language::{
+    abbreviate_vietnamese, get_char_as_str, Language, ShortTranslate, StaticTranslate, Translate,
+    DIZHI, DIZHI_EN, DIZHI_KO, DIZHI_VI, TIANGAN, TIANGAN_EN, TIANGAN_KO, TIANGAN_VI,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tiangan {
+    Jia,
+    Yi,
+    Bing,
+    Ding,
+    Wu,
+    Ji,
+    Geng,
+    Xin,
+    Ren,
+    Gui,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Dizhi {
+    Zi,
+    Chou,
+    Yin,
+    Mao,
+    Chen,
+    Si,
+    Wu,
+    Wei,
+    Shen,
+    You,
+    Xu,
+    Hai,
+}
+
+/// A pair of a stem and a branch of the same parity, the 60 of which form the sexagenary cycle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ganzhi {
+    tiangan: Tiangan,
+    dizhi: Dizhi,
+}
+
+/// Error of parsing a stem, a branch or a ganzhi.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseGanzhiError;
+
+impl Tiangan {
+    pub fn as_ordinal(self) -> u8 {
+        self as u8
+    }
+    pub fn from_ordinal(ordinal: u8) -> Option<Self> {
+        use Tiangan::*;
+        match ordinal {
+            0 => Some(Jia),
+            1 => Some(Yi),
+            2 => Some(Bing),
+            3 => Some(Ding),
+            4 => Some(Wu),
+            5 => Some(Ji),
+            6 => Some(Geng),
+            7 => Some(Xin),
+            8 => Some(Ren),
+            9 => Some(Gui),
+            _ => None,
+        }
+    }
+}
+
+impl Dizhi {
+    pub fn as_ordinal(self) -> u8 {
+        self as u8
+    }
+    pub fn from_ordinal(ordinal: u8) -> Option<Self> {
+        use Dizhi::*;
+        match ordinal {
+            0 => Some(Zi),
+            1 => Some(Chou),
+            2 => Some(Yin),
+            3 => Some(Mao),
+            4 => Some(Chen),
+            5 => Some(Si),
+            6 => Some(Wu),
+            7 => Some(Wei),
+            8 => Some(Shen),
+            9 => Some(You),
+            10 => Some(Xu),
+            11 => Some(Hai),
+            _ => None,
+        }
+    }
+}
+
+impl Ganzhi {
+    /// The pair of a stem and a branch, if they are of the same parity.
+    pub fn new(tiangan: Tiangan, dizhi: Dizhi) -> Option<Self> {
+        (tiangan.as_ordinal() % 2 == dizhi.as_ordinal() % 2).then_some(Self { tiangan, dizhi })
+    }
+    pub fn tiangan(self) -> Tiangan {
+        self.tiangan
+    }
+    pub fn dizhi(self) -> Dizhi {
+        self.dizhi
+    }
+    /// Ordinal in the cycle, from 0 for Jiazi to 59 for Guihai.
+    pub fn as_ordinal(self) -> u8 {
+        (self.tiangan.as_ordinal() as i8 * 6 - self.dizhi.as_ordinal() as i8 * 5).rem_euclid(60)
+            as u8
+    }
+    /// Ganzhi of a day, in the continuous cycle where 1949-10-01 is Jiazi.
+    pub fn of_day(date: &impl Datelike) -> Self {
+        Self::from_ordinal((date.num_days_from_ce() + 14).rem_euclid(60) as u8).unwrap()
+    }
+    pub fn from_ordinal(ordinal: u8) -> Option<Self> {
+        (ordinal < 60).then(|| Self {
+            tiangan: Tiangan::from_ordinal(ordinal % 10).unwrap(),
+            dizhi: Dizhi::from_ordinal(ordinal % 12).unwrap(),
+        })
+    }
+}
+
+/// Implements the arithmetic of a cycle: adding or subtracting steps, and the number of steps
+/// from one value forward to another.
+macro_rules! impl_cycle {
+    ($type:ty, $length:expr) => {
+        impl Add<i32> for $type {
+            type Output = Self;
+            fn add(self, steps: i32) -> Self {
+                let ordinal = (self.as_ordinal() as i32 + steps.rem_euclid($length)) % $length;
+                Self::from_ordinal(ordinal as u8).unwrap()
+            }
+        }
+
+        impl Sub<i32> for $type {
+            type Output = Self;
+            fn sub(self, steps: i32) -> Self {
+                self + ($length - steps.rem_euclid($length))
+            }
+        }
+
+        impl Sub for $type {
+            type Output = i32;
+            /// Steps from `other` forward to `self`, in `0..length`.
+            fn sub(self, other: Self) -> i32 {
+                (self.as_ordinal() as i32 - other.as_ordinal() as i32).rem_euclid($length)
+            }
+        }
+    };
+}
+
+impl_cycle!(Tiangan, 10);
+impl_cycle!(Dizhi, 12);
+impl_cycle!(Ganzhi, 60);
+
+/// Finds the value whose translation in any language is the string, ignoring case.
+fn parse<T: StaticTranslate>(s: &str, mut values: impl Iterator<Item = T>) -> Option<T> {
+    let s = s.trim().to_lowercase();
+    values.find(|value| {
+        Language::ALL
+            .iter()
+            .any(|&language| value.static_translate(language).to_lowercase() == s)
+    })
+}
+
+impl FromStr for Tiangan {
+    type Err = ParseGanzhiError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s, (0..10).filter_map(Self::from_ordinal)).ok_or(ParseGanzhiError)
+    }
+}
+
+impl FromStr for Dizhi {
+    type Err = ParseGanzhiError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s, (0..12).filter_map(Self::from_ordinal)).ok_or(ParseGanzhiError)
+    }
+}
+
+impl FromStr for Ganzhi {
+    type Err = ParseGanzhiError;
+    /// Parses a stem followed by a branch, as "甲子", "Jiazi", "Giáp Tý" or "갑자".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        // A stem is at most 4 characters long in any language
+        s.char_indices()
+            .skip(1)
+            .take(4)
+            .find_map(|(index, _)| {
+                let tiangan = s[..index].parse().ok()?;
+                let dizhi = s[index..].parse().ok()?;
+                Ganzhi::new(tiangan, dizhi)
+            })
+            .ok_or(ParseGanzhiError)
+    }
+}
+
+impl Display for ParseGanzhiError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "invalid heavenly stem or earthly branch")
+    }
+}
+
+impl Error for ParseGanzhiError {}
+
+impl StaticTranslate for Tiangan {
+    fn static_translate(&self, language: Language) -> &'static str {
+        let ordinal = self.as_ordinal() as usize;
+        match language {
+            Language::English => TIANGAN_EN[ordinal],
+            Language::Vietnamese => TIANGAN_VI[ordinal],
+            Language::Korean => get_char_as_str(TIANGAN_KO, ordinal).unwrap(),
+            _ => get_char_as_str(TIANGAN, ordinal).unwrap(),
+        }
+    }
+}
+
+impl StaticTranslate for Dizhi {
+    fn static_translate(&self, language: Language) -> &'static str {
+        let ordinal = self.as_ordinal() as usize;
+        match language {
+            Language::English => {
+                ["Zi", "Chou", "Yin", "Mao", "Chen", "Si", "Wu", "Wei", "Shen", "You", "Xu", "Hai"]
+                    [ordinal]
+            }
+            Language::Vietnamese => DIZHI_VI[ordinal],
+            Language::Korean => get_char_as_str(DIZHI_KO, ordinal).unwrap(),
+            _ => get_char_as_str(DIZHI, ordinal).unwrap(),
+        }
+    }
+}
+
+impl Translate for Tiangan {
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        self.static_translate(language).fmt(f)
+    }
+}
+
+impl ShortTranslate for Tiangan {
+    fn short_translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        self.translate(language, f)
+    }
+}
+
+impl Translate for Dizhi {
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        self.static_translate(language).fmt(f)
+    }
+}
+
+impl ShortTranslate for Dizhi {
+    fn short_translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        self.translate(language, f)
+    }
+}
+
+impl Translate for Ganzhi {
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        let tiangan = self.tiangan.static_translate(language);
+        match language {
+            Language::English => {
+                write!(f, "{tiangan}{}", DIZHI_EN[self.dizhi.as_ordinal() as usize])
+            }
+            Language::Vietnamese => {
+                write!(f, "{tiangan} {}", self.dizhi.static_translate(language))
+            }
+            _ => write!(f, "{tiangan}{}", self.dizhi.static_translate(language)),
+        }
+    }
+}
+
+impl ShortTranslate for Ganzhi {
+    fn short_translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        let translation = self.translate_to_string(language);
+        match language {
+            Language::English if translation.len() > 6 => write!(f, "{}.", &translation[..5]),
+            Language::Vietnamese => abbreviate_vietnamese(&translation, f),
+            _ => translation.fmt(f),
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    use Language::*;
+
+    for ordinal in 0..60 {
+        let ganzhi = Ganzhi::from_ordinal(ordinal).unwrap();
+        assert_eq!(ganzhi.as_ordinal(), ordinal);
+        assert_eq!(Ganzhi::new(ganzhi.tiangan(), ganzhi.dizhi()), Some(ganzhi));
+        for language in Language::ALL.iter().copied() {
+            assert_eq!(ganzhi.translate_to_string(language).parse(), Ok(ganzhi));
+        }
+    }
+    assert_eq!(Ganzhi::new(Tiangan::Jia, Dizhi::Chou), None);
+
+    assert_eq!(Tiangan::Gui + 1, Tiangan::Jia);
+    assert_eq!(Tiangan::Jia - 21, Tiangan::Gui);
+    assert_eq!(Dizhi::Zi - Dizhi::Hai, 1);
+    assert_eq!(Dizhi::Hai - Dizhi::Zi, 11);
+    let jiazi = Ganzhi::from_ordinal(0).unwrap();
+    let guihai = Ganzhi::from_ordinal(59).unwrap();
+    assert_eq!(jiazi - 1, guihai);
+    assert_eq!(guihai + 61, jiazi);
+    assert_eq!(jiazi - guihai, 1);
+    assert_eq!(jiazi + i32::MIN, jiazi + 52);
+
+    let date = chrono::NaiveDate::from_ymd_opt(1949, 10, 1).unwrap();
+    assert_eq!(Ganzhi::of_day(&date), jiazi);
+    assert_eq!(Ganzhi::of_day(&date.pred_opt().unwrap()), guihai);
+
+    let jiachen = Ganzhi::new(Tiangan::Jia, Dizhi::Chen).unwrap();
+    assert_eq!("甲辰".parse(), Ok(jiachen));
+    assert_eq!("jiachen".parse(), Ok(jiachen));
+    assert_eq!("JiaChen".parse(), Ok(jiachen));
+    assert_eq!("Giáp Thìn".parse(), Ok(jiachen));
+    assert_eq!("갑진".parse(), Ok(jiachen));
+    assert_eq!("甲丑".parse::<Ganzhi>(), Err(ParseGanzhiError));
+    assert_eq!("Chen".parse(), Ok(Dizhi::Chen));
+    assert_eq!("xin".parse(), Ok(Tiangan::Xin));
+    assert_eq!(jiachen.translate_to_string(English), "Jiachen");
+    assert_eq!(jiachen.translate_to_string(ChineseTraditional), "甲辰");
+    assert_eq!(
+        Ganzhi::from_ordinal(56)
+            .unwrap()
+            .short()
+            .translate_to_string(English),
+        "Gengs."
+    );
+}
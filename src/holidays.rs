@@ -0,0 +1,996 @@
+//! Public holidays and the weekend days made working days to bridge them (调休).
+
+use chrono::{Datelike, NaiveDate, TimeDelta, Weekday};
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::Arc,
+};
+
+use crate::{
+    is_weekend,
+    language::{Language, ShortTranslate, StaticTranslate, Translate},
+    ChineseDate, SolarTerm,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Holiday {
+    NewYear,
+    Chuxi,
+    Chunjie,
+    PeaceMemorialDay,
+    ChildrensDay,
+    Qingming,
+    GoodFriday,
+    HolySaturday,
+    EasterMonday,
+    LabourDay,
+    BuddhasBirthday,
+    Duanwu,
+    HksarDay,
+    Zhongqiu,
+    DayAfterZhongqiu,
+    ConfuciusDay,
+    NationalDay,
+    Chongyang,
+    RetrocessionDay,
+    AllSouls,
+    ImmaculateConception,
+    MsarDay,
+    Dongzhi,
+    ChristmasEve,
+    Christmas,
+    DayAfterChristmas,
+    ConstitutionDay,
+}
+
+/// How a date departs from the weekly rest days.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Arrangement {
+    /// A day off for a holiday (休).
+    Holiday(Holiday),
+    /// A weekend day made a working day (班).
+    Workday,
+}
+
+/// A source of holiday arrangements.
+pub trait HolidaySource {
+    /// The arrangement of a date, or `None` for an ordinary day or a year the source does not know.
+    fn arrangement(&self, date: NaiveDate) -> Option<Arrangement>;
+    /// Whether a date is a day off for a holiday.
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        matches!(self.arrangement(date), Some(Arrangement::Holiday(_)))
+    }
+    /// Whether a date is a working day, as the weekdays besides holidays and the weekend days made
+    /// working days.
+    fn is_workday(&self, date: NaiveDate) -> bool {
+        match self.arrangement(date) {
+            Some(Arrangement::Holiday(_)) => false,
+            Some(Arrangement::Workday) => true,
+            None => !is_weekend(date.weekday()),
+        }
+    }
+}
+
+/// A region with its own public holidays.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Region {
+    /// Mainland China, as arranged by the State Council.
+    #[default]
+    Mainland,
+    /// The general holidays of Hong Kong.
+    HongKong,
+    /// The public holidays of Macau.
+    Macau,
+    Taiwan,
+}
+
+/// A set of regions whose holidays are overlaid.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Regions(u8);
+
+type Sources = Vec<(Region, Arc<dyn HolidaySource + Send + Sync>)>;
+
+/// The holidays of a set of regions, with the sources of arrangements added for each, as for the
+/// years published after this release.
+#[derive(Clone, Default)]
+pub struct Holidays {
+    pub regions: Regions,
+    sources: Sources,
+}
+
+/// The arrangements of mainland China published by the State Council, from 2020 to 2026.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Mainland;
+
+type Arrangements = (Holiday, (i32, u32, u32), u8, &'static [(i32, u32, u32)]);
+
+/// Each holiday as its first day off, the number of days off and the working days.
+const MAINLAND: &[Arrangements] = {
+    use Holiday::*;
+    &[
+        (NewYear, (2020, 1, 1), 1, &[]),
+        (Chunjie, (2020, 1, 24), 10, &[(2020, 1, 19)]),
+        (Qingming, (2020, 4, 4), 3, &[]),
+        (LabourDay, (2020, 5, 1), 5, &[(2020, 4, 26), (2020, 5, 9)]),
+        (Duanwu, (2020, 6, 25), 3, &[(2020, 6, 28)]),
+        (
+            NationalDay,
+            (2020, 10, 1),
+            8,
+            &[(2020, 9, 27), (2020, 10, 10)],
+        ),
+        (NewYear, (2021, 1, 1), 3, &[]),
+        (Chunjie, (2021, 2, 11), 7, &[(2021, 2, 7), (2021, 2, 20)]),
+        (Qingming, (2021, 4, 3), 3, &[]),
+        (LabourDay, (2021, 5, 1), 5, &[(2021, 4, 25), (2021, 5, 8)]),
+        (Duanwu, (2021, 6, 12), 3, &[]),
+        (Zhongqiu, (2021, 9, 19), 3, &[(2021, 9, 18)]),
+        (
+            NationalDay,
+            (2021, 10, 1),
+            7,
+            &[(2021, 9, 26), (2021, 10, 9)],
+        ),
+        (NewYear, (2022, 1, 1), 3, &[]),
+        (Chunjie, (2022, 1, 31), 7, &[(2022, 1, 29), (2022, 1, 30)]),
+        (Qingming, (2022, 4, 3), 3, &[(2022, 4, 2)]),
+        (LabourDay, (2022, 4, 30), 5, &[(2022, 4, 24), (2022, 5, 7)]),
+        (Duanwu, (2022, 6, 3), 3, &[]),
+        (Zhongqiu, (2022, 9, 10), 3, &[]),
+        (
+            NationalDay,
+            (2022, 10, 1),
+            7,
+            &[(2022, 10, 8), (2022, 10, 9)],
+        ),
+        (NewYear, (2022, 12, 31), 3, &[]),
+        (Chunjie, (2023, 1, 21), 7, &[(2023, 1, 28), (2023, 1, 29)]),
+        (Qingming, (2023, 4, 5), 1, &[]),
+        (LabourDay, (2023, 4, 29), 5, &[(2023, 4, 23), (2023, 5, 6)]),
+        (Duanwu, (2023, 6, 22), 3, &[(2023, 6, 25)]),
+        (
+            NationalDay,
+            (2023, 9, 29),
+            8,
+            &[(2023, 10, 7), (2023, 10, 8)],
+        ),
+        (NewYear, (2024, 1, 1), 1, &[]),
+        (Chunjie, (2024, 2, 10), 8, &[(2024, 2, 4), (2024, 2, 18)]),
+        (Qingming, (2024, 4, 4), 3, &[(2024, 4, 7)]),
+        (LabourDay, (2024, 5, 1), 5, &[(2024, 4, 28), (2024, 5, 11)]),
+        (Duanwu, (2024, 6, 10), 1, &[]),
+        (Zhongqiu, (2024, 9, 15), 3, &[(2024, 9, 14)]),
+        (
+            NationalDay,
+            (2024, 10, 1),
+            7,
+            &[(2024, 9, 29), (2024, 10, 12)],
+        ),
+        (NewYear, (2025, 1, 1), 1, &[]),
+        (Chunjie, (2025, 1, 28), 8, &[(2025, 1, 26), (2025, 2, 8)]),
+        (Qingming, (2025, 4, 4), 3, &[]),
+        (LabourDay, (2025, 5, 1), 5, &[(2025, 4, 27)]),
+        (Duanwu, (2025, 5, 31), 3, &[]),
+        (
+            NationalDay,
+            (2025, 10, 1),
+            8,
+            &[(2025, 9, 28), (2025, 10, 11)],
+        ),
+        (NewYear, (2026, 1, 1), 3, &[(2026, 1, 4)]),
+        (Chunjie, (2026, 2, 15), 9, &[(2026, 2, 14), (2026, 2, 28)]),
+        (Qingming, (2026, 4, 4), 3, &[]),
+        (LabourDay, (2026, 5, 1), 5, &[(2026, 5, 9)]),
+        (Duanwu, (2026, 6, 19), 3, &[]),
+        (Zhongqiu, (2026, 9, 25), 3, &[]),
+        (
+            NationalDay,
+            (2026, 10, 1),
+            7,
+            &[(2026, 9, 20), (2026, 10, 10)],
+        ),
+    ]
+};
+
+fn from_table(table: &[Arrangements], date: NaiveDate) -> Option<Arrangement> {
+    let ymd = (date.year(), date.month(), date.day());
+    table
+        .iter()
+        .find_map(|&(holiday, (year, month, day), days, workdays)| {
+            let start = NaiveDate::from_ymd_opt(year, month, day)?;
+            if (start..start + TimeDelta::days(days as i64)).contains(&date) {
+                Some(Arrangement::Holiday(holiday))
+            } else {
+                workdays.contains(&ymd).then_some(Arrangement::Workday)
+            }
+        })
+}
+
+impl HolidaySource for Mainland {
+    fn arrangement(&self, date: NaiveDate) -> Option<Arrangement> {
+        from_table(MAINLAND, date)
+    }
+}
+
+/// The general holidays of Hong Kong, where a holiday on a Sunday or on another holiday is made up
+/// on the next day which is neither.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HongKong;
+
+/// The public holidays of Macau, where a holiday on a weekend or on another holiday is made up on
+/// the next working day, as in the public administration.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Macau;
+
+/// The national holidays of Taiwan, where a holiday on a Saturday is moved to the Friday before and
+/// one on a Sunday to the Monday after, and the Lunar New Year holidays on a weekend are made up
+/// after them, with the bridge days published by the DGPA from 2020 to 2025.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Taiwan;
+
+/// Each bridge day (彈性放假) as the holiday it bridges, and the Saturday worked for it (補班).
+const TAIWAN: &[Arrangements] = {
+    use Holiday::*;
+    &[
+        (Chunjie, (2020, 1, 23), 1, &[(2020, 2, 15)]),
+        (Duanwu, (2020, 6, 26), 1, &[(2020, 6, 20)]),
+        (Zhongqiu, (2020, 10, 2), 1, &[(2020, 9, 26)]),
+        (Chunjie, (2021, 2, 10), 1, &[(2021, 2, 20)]),
+        (Zhongqiu, (2021, 9, 20), 1, &[(2021, 9, 11)]),
+        (Chunjie, (2022, 2, 4), 1, &[(2022, 1, 22)]),
+        (Chunjie, (2023, 1, 20), 1, &[(2023, 1, 7)]),
+        (Chunjie, (2023, 1, 27), 1, &[(2023, 2, 4)]),
+        (PeaceMemorialDay, (2023, 2, 27), 1, &[(2023, 2, 18)]),
+        (ChildrensDay, (2023, 4, 3), 1, &[(2023, 3, 25)]),
+        (Duanwu, (2023, 6, 23), 1, &[(2023, 6, 17)]),
+        (NationalDay, (2023, 10, 9), 1, &[(2023, 9, 23)]),
+        (Chunjie, (2024, 2, 8), 1, &[(2024, 2, 17)]),
+        (Chunjie, (2025, 1, 27), 1, &[(2025, 2, 8)]),
+    ]
+};
+
+fn lunar(year: i32, month: u8, day: u8) -> Option<NaiveDate> {
+    ChineseDate::new(year, month, false, day).map(|date| date.to_gregorian())
+}
+
+/// Easter Sunday of a year in the Gregorian calendar.
+fn easter(year: i32) -> Option<NaiveDate> {
+    let a = year.rem_euclid(19);
+    let (b, c) = (year.div_euclid(100), year.rem_euclid(100));
+    let (d, e) = (b / 4, b % 4);
+    let g = (8 * b + 13) / 25;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let (i, k) = (c / 4, c % 4);
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 19 * l) / 433;
+    let month = (h + l - 7 * m + 90) / 25;
+    let day = (h + l - 7 * m + 33 * month + 19) % 32;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+/// Adds a day for each holiday on a rest day or on another holiday, the first after it which is
+/// neither.
+fn make_up(
+    holidays: Vec<(NaiveDate, Holiday)>, rest_days: &[Weekday],
+) -> Vec<(NaiveDate, Holiday)> {
+    let mut days: Vec<(NaiveDate, Holiday)> = Vec::with_capacity(holidays.len() + 4);
+    let mut moved = Vec::new();
+    for (date, holiday) in holidays {
+        if days.iter().any(|(day, _)| *day == date) {
+            moved.push((date, holiday));
+        } else {
+            if rest_days.contains(&date.weekday()) {
+                moved.push((date, holiday));
+            }
+            days.push((date, holiday));
+        }
+    }
+    for (mut date, holiday) in moved {
+        while rest_days.contains(&date.weekday()) || days.iter().any(|(day, _)| *day == date) {
+            date = date.succ_opt().unwrap();
+        }
+        days.push((date, holiday));
+    }
+    days
+}
+
+fn ymd(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(year, month, day)
+}
+
+impl HongKong {
+    fn holidays(year: i32) -> Vec<(NaiveDate, Holiday)> {
+        use Holiday::*;
+        let easter = easter(year);
+        let christmas = ymd(year, 12, 25);
+        let holidays = [
+            (ymd(year, 1, 1), NewYear),
+            (lunar(year, 1, 1), Chunjie),
+            (lunar(year, 1, 2), Chunjie),
+            (lunar(year, 1, 3), Chunjie),
+            (easter.map(|date| date - TimeDelta::days(2)), GoodFriday),
+            (easter.map(|date| date - TimeDelta::days(1)), HolySaturday),
+            (SolarTerm::Qingming.date(year), Qingming),
+            (easter.map(|date| date + TimeDelta::days(1)), EasterMonday),
+            (ymd(year, 5, 1), LabourDay),
+            (lunar(year, 4, 8), BuddhasBirthday),
+            (lunar(year, 5, 5), Duanwu),
+            (ymd(year, 7, 1), HksarDay),
+            (lunar(year, 8, 16), DayAfterZhongqiu),
+            (ymd(year, 10, 1), NationalDay),
+            (lunar(year, 9, 9), Chongyang),
+            (christmas, Christmas),
+            (
+                christmas.and_then(|date| date.succ_opt()).map(|date| {
+                    if date.weekday() == Weekday::Sun {
+                        date.succ_opt().unwrap()
+                    } else {
+                        date
+                    }
+                }),
+                DayAfterChristmas,
+            ),
+        ];
+        make_up(
+            holidays
+                .into_iter()
+                .filter_map(|(date, holiday)| Some((date?, holiday)))
+                .collect(),
+            &[Weekday::Sun],
+        )
+    }
+}
+
+impl Macau {
+    fn holidays(year: i32) -> Vec<(NaiveDate, Holiday)> {
+        use Holiday::*;
+        let easter = easter(year);
+        let holidays = [
+            (ymd(year, 1, 1), NewYear),
+            (lunar(year, 1, 1), Chunjie),
+            (lunar(year, 1, 2), Chunjie),
+            (lunar(year, 1, 3), Chunjie),
+            (easter.map(|date| date - TimeDelta::days(2)), GoodFriday),
+            (easter.map(|date| date - TimeDelta::days(1)), HolySaturday),
+            (SolarTerm::Qingming.date(year), Qingming),
+            (ymd(year, 5, 1), LabourDay),
+            (lunar(year, 4, 8), BuddhasBirthday),
+            (lunar(year, 5, 5), Duanwu),
+            (lunar(year, 8, 16), DayAfterZhongqiu),
+            (ymd(year, 10, 1), NationalDay),
+            (ymd(year, 10, 2), NationalDay),
+            (lunar(year, 9, 9), Chongyang),
+            (ymd(year, 11, 2), AllSouls),
+            (ymd(year, 12, 8), ImmaculateConception),
+            (ymd(year, 12, 20), MsarDay),
+            (SolarTerm::Dongzhi.date(year), Dongzhi),
+            (ymd(year, 12, 24), ChristmasEve),
+            (ymd(year, 12, 25), Christmas),
+        ];
+        make_up(
+            holidays
+                .into_iter()
+                .filter_map(|(date, holiday)| Some((date?, holiday)))
+                .collect(),
+            &[Weekday::Sat, Weekday::Sun],
+        )
+    }
+}
+
+impl Taiwan {
+    fn holidays(year: i32) -> Vec<(NaiveDate, Holiday)> {
+        use Holiday::*;
+        let mut days = Vec::new();
+        // The eve and the first 3 days of the Lunar New Year, made up on the weekdays after them
+        if let Some(chunjie) = lunar(year, 1, 1) {
+            let mut date = chunjie.pred_opt().unwrap();
+            let mut weekend = 0;
+            for holiday in [Chuxi, Chunjie, Chunjie, Chunjie] {
+                weekend += is_weekend(date.weekday()) as u8;
+                days.push((date, holiday));
+                date = date.succ_opt().unwrap();
+            }
+            while weekend > 0 {
+                if !is_weekend(date.weekday()) {
+                    days.push((date, Chunjie));
+                    weekend -= 1;
+                }
+                date = date.succ_opt().unwrap();
+            }
+        }
+        let qingming = SolarTerm::Qingming.date(year);
+        // Children's Day on Qingming is the day before, or the day after on a Thursday
+        let childrens_day = ymd(year, 4, 4).map(|date| {
+            if Some(date) != qingming {
+                date
+            } else if date.weekday() == Weekday::Thu {
+                date.succ_opt().unwrap()
+            } else {
+                date.pred_opt().unwrap()
+            }
+        });
+        // Labour Day, Confucius' Birthday, Retrocession Day and Constitution Day are kept by all
+        // since 2025
+        let since_2025 = |date: Option<NaiveDate>| date.filter(|_| year >= 2025);
+        let holidays = [
+            (ymd(year, 1, 1), NewYear),
+            (ymd(year, 2, 28), PeaceMemorialDay),
+            (childrens_day, ChildrensDay),
+            (qingming, Qingming),
+            (since_2025(ymd(year, 5, 1)), LabourDay),
+            (lunar(year, 5, 5), Duanwu),
+            (lunar(year, 8, 15), Zhongqiu),
+            (since_2025(ymd(year, 9, 28)), ConfuciusDay),
+            (ymd(year, 10, 10), NationalDay),
+            (since_2025(ymd(year, 10, 25)), RetrocessionDay),
+            (since_2025(ymd(year, 12, 25)), ConstitutionDay),
+        ];
+        let holidays: Vec<_> = holidays
+            .into_iter()
+            .filter_map(|(date, holiday)| Some((date?, holiday)))
+            .collect();
+        days.extend(&holidays);
+        // A day moved onto another holiday goes on in the same direction, as Qingming of 2020 on a
+        // Saturday, moved past Children's Day on the Friday to the Thursday
+        for (mut date, holiday) in holidays {
+            let step = match date.weekday() {
+                Weekday::Sat => TimeDelta::days(-1),
+                Weekday::Sun => TimeDelta::days(1),
+                _ => continue,
+            };
+            while is_weekend(date.weekday()) || days.iter().any(|(day, _)| *day == date) {
+                date += step;
+            }
+            days.push((date, holiday));
+        }
+        days
+    }
+}
+
+/// The holiday on a date among those of its year and of the years around it, whose days off can be
+/// moved across New Year.
+fn from_list(
+    holidays: fn(i32) -> Vec<(NaiveDate, Holiday)>, date: NaiveDate,
+) -> Option<Arrangement> {
+    let year = date.year();
+    [year, year - 1, year + 1].into_iter().find_map(|year| {
+        holidays(year)
+            .into_iter()
+            .find(|(day, _)| *day == date)
+            .map(|(_, holiday)| Arrangement::Holiday(holiday))
+    })
+}
+
+impl HolidaySource for HongKong {
+    fn arrangement(&self, date: NaiveDate) -> Option<Arrangement> {
+        from_list(Self::holidays, date)
+    }
+}
+
+impl HolidaySource for Macau {
+    fn arrangement(&self, date: NaiveDate) -> Option<Arrangement> {
+        from_list(Self::holidays, date)
+    }
+}
+
+impl HolidaySource for Taiwan {
+    fn arrangement(&self, date: NaiveDate) -> Option<Arrangement> {
+        from_list(Self::holidays, date).or_else(|| from_table(TAIWAN, date))
+    }
+}
+
+impl Region {
+    pub const ALL: &'static [Region] =
+        &[Region::Mainland, Region::HongKong, Region::Macau, Region::Taiwan];
+}
+
+impl HolidaySource for Region {
+    fn arrangement(&self, date: NaiveDate) -> Option<Arrangement> {
+        match self {
+            Region::Mainland => Mainland.arrangement(date),
+            Region::HongKong => HongKong.arrangement(date),
+            Region::Macau => Macau.arrangement(date),
+            Region::Taiwan => Taiwan.arrangement(date),
+        }
+    }
+}
+
+impl Regions {
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+    pub fn contains(self, region: Region) -> bool {
+        self.0 & 1 << region as u8 != 0
+    }
+    pub fn insert(&mut self, region: Region) {
+        self.0 |= 1 << region as u8;
+    }
+    pub fn iter(self) -> impl Iterator<Item = Region> {
+        Region::ALL
+            .iter()
+            .copied()
+            .filter(move |region| self.contains(*region))
+    }
+    /// The arrangements of a date in each region, in the order of [`Region::ALL`].
+    pub fn arrangements(self, date: NaiveDate) -> impl Iterator<Item = (Region, Arrangement)> {
+        self.iter()
+            .filter_map(move |region| Some((region, region.arrangement(date)?)))
+    }
+}
+
+impl From<Region> for Regions {
+    fn from(region: Region) -> Self {
+        Regions(1 << region as u8)
+    }
+}
+
+impl FromIterator<Region> for Regions {
+    fn from_iter<T: IntoIterator<Item = Region>>(iter: T) -> Self {
+        let mut regions = Regions::default();
+        for region in iter {
+            regions.insert(region);
+        }
+        regions
+    }
+}
+
+impl Holidays {
+    pub fn new(regions: Regions) -> Self {
+        Self {
+            regions,
+            sources: Vec::new(),
+        }
+    }
+    /// Adds a source of arrangements for a region, consulted on the dates the built-in ones leave
+    /// ordinary.
+    pub fn add_source(&mut self, region: Region, source: Arc<dyn HolidaySource + Send + Sync>) {
+        self.sources.push((region, source));
+    }
+    /// The arrangement of a date in a region, from the built-in source or else the added ones.
+    pub fn arrangement(&self, region: Region, date: NaiveDate) -> Option<Arrangement> {
+        region.arrangement(date).or_else(|| {
+            self.sources
+                .iter()
+                .filter(|(source_region, _)| *source_region == region)
+                .find_map(|(_, source)| source.arrangement(date))
+        })
+    }
+    /// The arrangements of a date in each region, in the order of [`Region::ALL`].
+    pub fn arrangements(
+        &self, date: NaiveDate,
+    ) -> impl Iterator<Item = (Region, Arrangement)> + '_ {
+        self.regions
+            .iter()
+            .filter_map(move |region| Some((region, self.arrangement(region, date)?)))
+    }
+}
+
+impl From<Regions> for Holidays {
+    fn from(regions: Regions) -> Self {
+        Self::new(regions)
+    }
+}
+
+impl From<Region> for Holidays {
+    fn from(region: Region) -> Self {
+        Self::new(region.into())
+    }
+}
+
+impl std::fmt::Debug for Holidays {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Holidays")
+            .field("regions", &self.regions)
+            .field(
+                "sources",
+                &self
+                    .sources
+                    .iter()
+                    .map(|(region, _)| region)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+/// Holidays are equal with the same regions and the same sources, in the same order.
+impl PartialEq for Holidays {
+    fn eq(&self, other: &Self) -> bool {
+        self.regions == other.regions
+            && self.sources.len() == other.sources.len()
+            && self
+                .sources
+                .iter()
+                .zip(&other.sources)
+                .all(|(a, b)| a.0 == b.0 && Arc::ptr_eq(&a.1, &b.1))
+    }
+}
+
+impl Eq for Holidays {}
+
+impl StaticTranslate for Region {
+    fn static_translate(&self, language: Language) -> &'static str {
+        let ordinal = *self as usize;
+        match language {
+            Language::English => ["Mainland China", "Hong Kong", "Macau", "Taiwan"][ordinal],
+            Language::ChineseSimplified => ["中国大陆", "香港", "澳门", "台湾"][ordinal],
+            Language::ChineseTraditional => ["中國大陸", "香港", "澳門", "臺灣"][ordinal],
+            Language::Vietnamese => {
+                ["Trung Quốc đại lục", "Hồng Kông", "Ma Cao", "Đài Loan"][ordinal]
+            }
+            Language::Korean => ["중국 대륙", "홍콩", "마카오", "대만"][ordinal],
+            Language::Japanese => ["中国大陸", "香港", "マカオ", "台湾"][ordinal],
+        }
+    }
+}
+
+impl Translate for Region {
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        self.static_translate(language).fmt(f)
+    }
+}
+
+impl StaticTranslate for Holiday {
+    fn static_translate(&self, language: Language) -> &'static str {
+        use Holiday::*;
+        use Language::*;
+        match language {
+            English => match self {
+                NewYear => "New Year's Day",
+                Chuxi => "Lunar New Year's Eve",
+                Chunjie => "Spring Festival",
+                PeaceMemorialDay => "Peace Memorial Day",
+                ChildrensDay => "Children's Day",
+                Qingming => "Qingming Festival",
+                GoodFriday => "Good Friday",
+                HolySaturday => "Holy Saturday",
+                EasterMonday => "Easter Monday",
+                LabourDay => "Labour Day",
+                BuddhasBirthday => "Buddha's Birthday",
+                Duanwu => "Dragon Boat Festival",
+                HksarDay => "HKSAR Establishment Day",
+                Zhongqiu => "Mid-Autumn Festival",
+                DayAfterZhongqiu => "Day after Mid-Autumn Festival",
+                ConfuciusDay => "Confucius' Birthday",
+                NationalDay => "National Day",
+                Chongyang => "Chung Yeung Festival",
+                RetrocessionDay => "Retrocession Day",
+                AllSouls => "All Souls' Day",
+                ImmaculateConception => "Immaculate Conception",
+                MsarDay => "MSAR Establishment Day",
+                Dongzhi => "Winter Solstice",
+                ChristmasEve => "Christmas Eve",
+                Christmas => "Christmas Day",
+                DayAfterChristmas => "First weekday after Christmas",
+                ConstitutionDay => "Constitution Day",
+            },
+            ChineseSimplified => match self {
+                NewYear => "元旦",
+                Chuxi => "除夕",
+                Chunjie => "春节",
+                PeaceMemorialDay => "和平纪念日",
+                ChildrensDay => "儿童节",
+                Qingming => "清明节",
+                GoodFriday => "耶稣受难节",
+                HolySaturday => "耶稣受难节翌日",
+                EasterMonday => "复活节星期一",
+                LabourDay => "劳动节",
+                BuddhasBirthday => "佛诞",
+                Duanwu => "端午节",
+                HksarDay => "香港特别行政区成立纪念日",
+                Zhongqiu => "中秋节",
+                DayAfterZhongqiu => "中秋节翌日",
+                ConfuciusDay => "孔子诞辰纪念日",
+                NationalDay => "国庆节",
+                Chongyang => "重阳节",
+                RetrocessionDay => "台湾光复节",
+                AllSouls => "追思节",
+                ImmaculateConception => "圣母无原罪瞻礼",
+                MsarDay => "澳门特别行政区成立纪念日",
+                Dongzhi => "冬至",
+                ChristmasEve => "圣诞节前夕",
+                Christmas => "圣诞节",
+                DayAfterChristmas => "圣诞节后第一个工作日",
+                ConstitutionDay => "行宪纪念日",
+            },
+            ChineseTraditional => match self {
+                NewYear => "元旦",
+                Chuxi => "除夕",
+                Chunjie => "春節",
+                PeaceMemorialDay => "和平紀念日",
+                ChildrensDay => "兒童節",
+                Qingming => "清明節",
+                GoodFriday => "耶穌受難節",
+                HolySaturday => "耶穌受難節翌日",
+                EasterMonday => "復活節星期一",
+                LabourDay => "勞動節",
+                BuddhasBirthday => "佛誕",
+                Duanwu => "端午節",
+                HksarDay => "香港特別行政區成立紀念日",
+                Zhongqiu => "中秋節",
+                DayAfterZhongqiu => "中秋節翌日",
+                ConfuciusDay => "孔子誕辰紀念日",
+                NationalDay => "國慶節",
+                Chongyang => "重陽節",
+                RetrocessionDay => "臺灣光復節",
+                AllSouls => "追思節",
+                ImmaculateConception => "聖母無原罪瞻禮",
+                MsarDay => "澳門特別行政區成立紀念日",
+                Dongzhi => "冬至",
+                ChristmasEve => "聖誕節前夕",
+                Christmas => "聖誕節",
+                DayAfterChristmas => "聖誕節後第一個工作日",
+                ConstitutionDay => "行憲紀念日",
+            },
+            Vietnamese => match self {
+                NewYear => "Tết Dương lịch",
+                Chuxi => "Giao thừa",
+                Chunjie => "Tết Nguyên Đán",
+                PeaceMemorialDay => "Ngày Tưởng niệm Hòa bình",
+                ChildrensDay => "Ngày Thiếu nhi",
+                Qingming => "Tết Thanh minh",
+                GoodFriday => "Thứ Sáu Tuần Thánh",
+                HolySaturday => "Thứ Bảy Tuần Thánh",
+                EasterMonday => "Thứ Hai Phục Sinh",
+                LabourDay => "Ngày Quốc tế Lao động",
+                BuddhasBirthday => "Lễ Phật đản",
+                Duanwu => "Tết Đoan Ngọ",
+                HksarDay => "Ngày thành lập Đặc khu Hồng Kông",
+                Zhongqiu => "Tết Trung Thu",
+                DayAfterZhongqiu => "Ngày sau Tết Trung Thu",
+                ConfuciusDay => "Ngày sinh Khổng Tử",
+                NationalDay => "Quốc khánh",
+                Chongyang => "Tết Trùng Cửu",
+                RetrocessionDay => "Ngày Quang phục Đài Loan",
+                AllSouls => "Lễ Các Đẳng",
+                ImmaculateConception => "Lễ Đức Mẹ Vô Nhiễm",
+                MsarDay => "Ngày thành lập Đặc khu Ma Cao",
+                Dongzhi => "Đông chí",
+                ChristmasEve => "Đêm Giáng sinh",
+                Christmas => "Lễ Giáng sinh",
+                DayAfterChristmas => "Ngày làm việc đầu tiên sau Giáng sinh",
+                ConstitutionDay => "Ngày Hiến pháp",
+            },
+            Korean => match self {
+                NewYear => "신정",
+                Chuxi => "섣달 그믐",
+                Chunjie => "춘절",
+                PeaceMemorialDay => "평화기념일",
+                ChildrensDay => "어린이날",
+                Qingming => "청명절",
+                GoodFriday => "성금요일",
+                HolySaturday => "성토요일",
+                EasterMonday => "부활절 월요일",
+                LabourDay => "노동절",
+                BuddhasBirthday => "석가탄신일",
+                Duanwu => "단오절",
+                HksarDay => "홍콩 특별행정구 수립 기념일",
+                Zhongqiu => "중추절",
+                DayAfterZhongqiu => "중추절 다음 날",
+                ConfuciusDay => "공자 탄신 기념일",
+                NationalDay => "국경절",
+                Chongyang => "중양절",
+                RetrocessionDay => "대만 광복절",
+                AllSouls => "위령의 날",
+                ImmaculateConception => "원죄 없으신 잉태 대축일",
+                MsarDay => "마카오 특별행정구 수립 기념일",
+                Dongzhi => "동지",
+                ChristmasEve => "크리스마스 이브",
+                Christmas => "크리스마스",
+                DayAfterChristmas => "크리스마스 후 첫 평일",
+                ConstitutionDay => "제헌절",
+            },
+            Japanese => match self {
+                NewYear => "元日",
+                Chuxi => "除夕",
+                Chunjie => "春節",
+                PeaceMemorialDay => "和平記念日",
+                ChildrensDay => "児童節",
+                Qingming => "清明節",
+                GoodFriday => "聖金曜日",
+                HolySaturday => "聖土曜日",
+                EasterMonday => "復活祭月曜日",
+                LabourDay => "労働節",
+                BuddhasBirthday => "仏誕節",
+                Duanwu => "端午節",
+                HksarDay => "香港特別行政区設立記念日",
+                Zhongqiu => "中秋節",
+                DayAfterZhongqiu => "中秋節翌日",
+                ConfuciusDay => "孔子誕生記念日",
+                NationalDay => "国慶節",
+                Chongyang => "重陽節",
+                RetrocessionDay => "台湾光復節",
+                AllSouls => "死者の日",
+                ImmaculateConception => "無原罪の聖母",
+                MsarDay => "マカオ特別行政区設立記念日",
+                Dongzhi => "冬至",
+                ChristmasEve => "クリスマス・イブ",
+                Christmas => "クリスマス",
+                DayAfterChristmas => "クリスマス後の最初の平日",
+                ConstitutionDay => "行憲記念日",
+            },
+        }
+    }
+}
+
+impl Translate for Holiday {
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        self.static_translate(language).fmt(f)
+    }
+}
+
+impl Translate for Arrangement {
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        match self {
+            Arrangement::Holiday(holiday) => holiday.translate(language, f),
+            Arrangement::Workday => match language {
+                Language::English => "Working day",
+                Language::ChineseSimplified => "调休上班",
+                Language::ChineseTraditional => "調休上班",
+                Language::Vietnamese => "Ngày làm bù",
+                Language::Korean => "대체 근무일",
+                Language::Japanese => "振替出勤日",
+            }
+            .fmt(f),
+        }
+    }
+}
+
+impl ShortTranslate for Arrangement {
+    /// The mark of phone calendars, as "休" and "班".
+    fn short_translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        let holiday = matches!(self, Arrangement::Holiday(_));
+        match language {
+            Language::English => if holiday { "H" } else { "W" }.fmt(f),
+            Language::Vietnamese => if holiday { "N" } else { "L" }.fmt(f),
+            Language::Korean => if holiday { "휴" } else { "근" }.fmt(f),
+            Language::Japanese => if holiday { "休" } else { "出" }.fmt(f),
+            Language::ChineseSimplified | Language::ChineseTraditional => {
+                if holiday { "休" } else { "班" }.fmt(f)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    use crate::date;
+    use Language::*;
+
+    // The National Day holidays of 2024 run from 10-01 to 10-07, with 09-29 and 10-12 worked
+    assert_eq!(
+        Mainland.arrangement(date(2024, 10, 7)),
+        Some(Arrangement::Holiday(Holiday::NationalDay))
+    );
+    assert!(Mainland.is_holiday(date(2024, 10, 5)));
+    assert!(!Mainland.is_workday(date(2024, 10, 5)));
+    assert!(Mainland.is_workday(date(2024, 9, 29)));
+    assert!(Mainland.is_workday(date(2024, 10, 8)));
+    assert!(!Mainland.is_holiday(date(2024, 10, 13)));
+    assert!(!Mainland.is_workday(date(2024, 10, 13)));
+    // New Year's Day of 2023 began on 2022-12-31
+    assert!(Mainland.is_holiday(date(2022, 12, 31)));
+
+    struct Published2030;
+    impl HolidaySource for Published2030 {
+        fn arrangement(&self, date: NaiveDate) -> Option<Arrangement> {
+            (date == NaiveDate::from_ymd_opt(2030, 1, 1).unwrap())
+                .then_some(Arrangement::Holiday(Holiday::NewYear))
+        }
+    }
+    let mut holidays = Holidays::from(Region::Mainland);
+    assert_eq!(
+        holidays.arrangement(Region::Mainland, date(2030, 1, 1)),
+        None
+    );
+    holidays.add_source(Region::Mainland, Arc::new(Published2030));
+    assert_eq!(
+        holidays.arrangements(date(2030, 1, 1)).collect::<Vec<_>>(),
+        [(Region::Mainland, Arrangement::Holiday(Holiday::NewYear))]
+    );
+    assert!(!Region::Mainland.is_holiday(date(2030, 1, 1)));
+    assert_ne!(holidays, Holidays::from(Region::Mainland));
+
+    assert_eq!(easter(2024), Some(date(2024, 3, 31)));
+    assert_eq!(easter(2025), Some(date(2025, 4, 20)));
+
+    // The 2nd day of the Lunar New Year of 2024 is a Sunday, made up after the 3rd day
+    assert!(HongKong.is_holiday(date(2024, 2, 13)));
+    assert!(HongKong.is_workday(date(2024, 2, 14)));
+    assert_eq!(
+        HongKong.arrangement(date(2024, 9, 18)),
+        Some(Arrangement::Holiday(Holiday::DayAfterZhongqiu))
+    );
+    // The 1st day of the Lunar New Year of 2023 is a Sunday, made up on the 4th day
+    assert_eq!(
+        HongKong.arrangement(date(2023, 1, 25)),
+        Some(Arrangement::Holiday(Holiday::Chunjie))
+    );
+    // Mid-Autumn of 2022 is a Saturday, and the day after a Sunday made up on the Monday
+    assert!(!HongKong.is_holiday(date(2022, 9, 10)));
+    assert_eq!(
+        HongKong.arrangement(date(2022, 9, 12)),
+        Some(Arrangement::Holiday(Holiday::DayAfterZhongqiu))
+    );
+    // Qingming of 2007 is on 04-05
+    assert!(HongKong.is_holiday(date(2007, 4, 5)));
+    // Buddha's Birthday of 2024 is on 05-15, and Christmas of 2022 is a Sunday
+    assert!(HongKong.is_holiday(date(2024, 5, 15)));
+    assert!(HongKong.is_holiday(date(2022, 12, 26)));
+    assert!(HongKong.is_holiday(date(2022, 12, 27)));
+    assert!(HongKong.is_workday(date(2022, 12, 28)));
+    assert!(Macau.is_holiday(date(2024, 12, 20)));
+    assert!(Macau.is_holiday(date(2024, 10, 2)));
+    // All Souls' Day of 2024 is a Saturday, made up on the Monday
+    assert!(Macau.is_holiday(date(2024, 11, 4)));
+
+    // The Lunar New Year of 2024 runs from the eve on 02-09 to 02-14, with the weekend made up
+    for day in 9..=14 {
+        assert!(Taiwan.is_holiday(date(2024, 2, day)), "{day}");
+    }
+    assert!(Taiwan.is_workday(date(2024, 2, 15)));
+    // Children's Day of 2024 is on Qingming, a Thursday, and moved to the Friday
+    assert_eq!(
+        Taiwan.arrangement(date(2024, 4, 5)),
+        Some(Arrangement::Holiday(Holiday::ChildrensDay))
+    );
+    // The bridge day before the eve, made up on the Saturday after the holidays
+    assert_eq!(
+        Taiwan.arrangement(date(2024, 2, 8)),
+        Some(Arrangement::Holiday(Holiday::Chunjie))
+    );
+    assert_eq!(
+        Taiwan.arrangement(date(2024, 2, 17)),
+        Some(Arrangement::Workday)
+    );
+    assert!(!Taiwan.is_workday(date(2024, 2, 24)));
+    assert!(Taiwan.is_holiday(date(2023, 10, 9)));
+    assert!(Taiwan.is_workday(date(2023, 9, 23)));
+    // New Year's Day of 2022 is a Saturday, moved to the Friday before
+    assert_eq!(
+        Taiwan.arrangement(date(2021, 12, 31)),
+        Some(Arrangement::Holiday(Holiday::NewYear))
+    );
+    // Children's Day of 2020 is on Qingming, a Saturday, and both are moved before the weekend
+    assert_eq!(
+        Taiwan.arrangement(date(2020, 4, 2)),
+        Some(Arrangement::Holiday(Holiday::Qingming))
+    );
+    assert_eq!(
+        Taiwan.arrangement(date(2020, 4, 3)),
+        Some(Arrangement::Holiday(Holiday::ChildrensDay))
+    );
+    // National Day of 2026 is a Saturday, moved to the Friday before
+    assert!(Taiwan.is_holiday(date(2026, 10, 9)));
+    assert!(!Region::Taiwan.is_holiday(date(2024, 12, 25)));
+    assert!(Region::Taiwan.is_holiday(date(2025, 12, 25)));
+
+    let regions: Regions = [Region::Mainland, Region::HongKong].into_iter().collect();
+    assert_eq!(regions.len(), 2);
+    assert!(!regions.contains(Region::Taiwan));
+    assert_eq!(
+        regions.arrangements(date(2024, 7, 1)).collect::<Vec<_>>(),
+        [(Region::HongKong, Arrangement::Holiday(Holiday::HksarDay))]
+    );
+
+    assert_eq!(
+        Arrangement::Workday
+            .short()
+            .translate_to_string(ChineseSimplified),
+        "班"
+    );
+    assert_eq!(
+        Arrangement::Holiday(Holiday::NationalDay).translate_to_string(ChineseTraditional),
+        "國慶節"
+    );
+    assert_eq!(
+        Holiday::DayAfterChristmas.translate_to_string(ChineseSimplified),
+        "圣诞节后第一个工作日"
+    );
+}
@@ -0,0 +1,180 @@
+//! The Japanese old calendar (旧暦, kyūreki), which follows the rules of the Chinese calendar
+//! reckoned at UTC+9, and the six-day rokuyō cycle derived from it.
+
+use chrono::{Datelike, NaiveDate};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{
+    chinese_date::{days_of_chinese_month_at, ChineseYear},
+    language::{Language, ShortTranslate, StaticTranslate, Translate},
+    ChineseDate,
+};
+
+/// UTC offset of Japan, in seconds.
+pub const JAPAN_OFFSET: i32 = 9 * 3600;
+
+/// A date in the Japanese old calendar, whose year, month and day are labelled as in
+/// [`ChineseDate`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct JapaneseDate(pub ChineseDate);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rokuyo {
+    Sensho,
+    Tomobiki,
+    Senbu,
+    Butsumetsu,
+    Taian,
+    Shakko,
+}
+
+/// Gregorian date of the old new year (旧正月) in a Japanese year.
+pub fn kyu_shogatsu(year: ChineseYear) -> Option<NaiveDate> {
+    crate::chinese_date::chunjie_at(year, Some(JAPAN_OFFSET))
+}
+
+impl JapaneseDate {
+    pub fn new(year: i32, month: u8, leap: bool, day: u8) -> Option<Self> {
+        ChineseDate::new(year, month, leap, day).map(Self)
+    }
+    pub fn from_gregorian(date: &impl Datelike) -> Option<Self> {
+        let date = NaiveDate::from_ymd_opt(date.year(), date.month(), date.day())?;
+        ChineseDate::from_gregorian_at(date, Some(JAPAN_OFFSET)).map(Self)
+    }
+    pub fn to_gregorian(&self) -> NaiveDate {
+        self.0.to_gregorian_at(Some(JAPAN_OFFSET)).unwrap()
+    }
+    pub fn days_of_month(&self) -> u8 {
+        days_of_chinese_month_at(
+            self.0.chinese_year(),
+            self.0.chinese_month(),
+            Some(JAPAN_OFFSET),
+        )
+        .unwrap()
+    }
+    pub fn rokuyo(&self) -> Rokuyo {
+        Rokuyo::from_japanese_date(*self)
+    }
+}
+
+impl Rokuyo {
+    pub fn as_ordinal(self) -> u8 {
+        self as u8
+    }
+    pub fn from_ordinal(ordinal: u8) -> Option<Self> {
+        use Rokuyo::*;
+        match ordinal {
+            0 => Some(Sensho),
+            1 => Some(Tomobiki),
+            2 => Some(Senbu),
+            3 => Some(Butsumetsu),
+            4 => Some(Taian),
+            5 => Some(Shakko),
+            _ => None,
+        }
+    }
+    /// The rokuyō of a day, which starts from Sensho on the first month and advances by one each
+    /// month and each day. A leap month counts as the month it follows.
+    pub fn from_japanese_date(date: JapaneseDate) -> Self {
+        Self::from_ordinal((date.0.month() + date.0.day() + 4) % 6).unwrap()
+    }
+}
+
+impl StaticTranslate for Rokuyo {
+    fn static_translate(&self, language: Language) -> &'static str {
+        use Language::*;
+        use Rokuyo::*;
+        match language {
+            English => match self {
+                Sensho => "Sensho",
+                Tomobiki => "Tomobiki",
+                Senbu => "Senbu",
+                Butsumetsu => "Butsumetsu",
+                Taian => "Taian",
+                Shakko => "Shakko",
+            },
+            ChineseSimplified => match self {
+                Sensho => "先胜",
+                Tomobiki => "友引",
+                Senbu => "先负",
+                Butsumetsu => "佛灭",
+                Taian => "大安",
+                Shakko => "赤口",
+            },
+            ChineseTraditional => match self {
+                Sensho => "先勝",
+                Tomobiki => "友引",
+                Senbu => "先負",
+                Butsumetsu => "佛滅",
+                Taian => "大安",
+                Shakko => "赤口",
+            },
+            Vietnamese => match self {
+                Sensho => "Tiên thắng",
+                Tomobiki => "Hữu dẫn",
+                Senbu => "Tiên phụ",
+                Butsumetsu => "Phật diệt",
+                Taian => "Đại an",
+                Shakko => "Xích khẩu",
+            },
+            Korean => match self {
+                Sensho => "선승",
+                Tomobiki => "우인",
+                Senbu => "선부",
+                Butsumetsu => "불멸",
+                Taian => "대안",
+                Shakko => "적구",
+            },
+            Japanese => match self {
+                Sensho => "先勝",
+                Tomobiki => "友引",
+                Senbu => "先負",
+                Butsumetsu => "仏滅",
+                Taian => "大安",
+                Shakko => "赤口",
+            },
+        }
+    }
+}
+
+impl Translate for Rokuyo {
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        self.static_translate(language).fmt(f)
+    }
+}
+
+impl ShortTranslate for Rokuyo {
+    fn short_translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        let translation = self.static_translate(language);
+        if !language.is_cjk() && translation.chars().count() > 6 {
+            write!(f, "{}.", translation.chars().take(5).collect::<String>())
+        } else {
+            translation.fmt(f)
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    // The old new year of 1997 is a day later than Chunjie, as in Korea
+    let year = ChineseYear::new(1997).unwrap();
+    assert_eq!(kyu_shogatsu(year), NaiveDate::from_ymd_opt(1997, 2, 8));
+    assert_eq!(
+        crate::chinese_date::chunjie(year),
+        NaiveDate::from_ymd_opt(1997, 2, 7)
+    );
+
+    for ((year, month, day), rokuyo) in [
+        ((2025, 1, 29), Rokuyo::Sensho),
+        ((2024, 12, 31), Rokuyo::Shakko),
+        ((2025, 4, 1), Rokuyo::Shakko),
+        // A leap month counts as the month it follows
+        ((2025, 8, 8), Rokuyo::Tomobiki),
+    ] {
+        let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        let japanese_date = JapaneseDate::from_gregorian(&date).unwrap();
+        assert_eq!(japanese_date.rokuyo(), rokuyo);
+        assert_eq!(japanese_date.to_gregorian(), date);
+    }
+}
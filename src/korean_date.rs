@@ -0,0 +1,78 @@
+//! The Korean lunisolar calendar (음력), which follows the rules of the Chinese calendar reckoned
+//! at UTC+9.
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::{
+    chinese_date::{chunjie_at, days_of_chinese_month_at, ChineseYear},
+    ChineseDate,
+};
+
+/// UTC offset of Korea, in seconds.
+pub const KOREA_OFFSET: i32 = 9 * 3600;
+/// Dangi year of the first Gregorian year, counted from the legendary founding of Gojoseon.
+pub const DANGI_OFFSET: i32 = 2333;
+
+/// A date in the Korean calendar, whose year, month and day are labelled as in [`ChineseDate`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct KoreanDate(pub ChineseDate);
+
+/// Gregorian date of Seollal in a Korean year.
+pub fn seollal(year: ChineseYear) -> Option<NaiveDate> {
+    chunjie_at(year, Some(KOREA_OFFSET))
+}
+
+impl KoreanDate {
+    pub fn new(year: i32, month: u8, leap: bool, day: u8) -> Option<Self> {
+        ChineseDate::new(year, month, leap, day).map(Self)
+    }
+    pub fn from_gregorian(date: &impl Datelike) -> Option<Self> {
+        let date = NaiveDate::from_ymd_opt(date.year(), date.month(), date.day())?;
+        ChineseDate::from_gregorian_at(date, Some(KOREA_OFFSET)).map(Self)
+    }
+    pub fn to_gregorian(&self) -> NaiveDate {
+        self.0.to_gregorian_at(Some(KOREA_OFFSET)).unwrap()
+    }
+    pub fn days_of_month(&self) -> u8 {
+        days_of_chinese_month_at(
+            self.0.chinese_year(),
+            self.0.chinese_month(),
+            Some(KOREA_OFFSET),
+        )
+        .unwrap()
+    }
+    /// The year in the Dangi era.
+    pub fn dangi_year(&self) -> i32 {
+        self.0.year() + DANGI_OFFSET
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    use crate::chinese_date::chunjie;
+
+    // Years whose Seollal differs from Chunjie
+    for (year, seollal_date, chunjie_date) in
+        [(1997, (1997, 2, 8), (1997, 2, 7)), (2027, (2027, 2, 7), (2027, 2, 6))]
+    {
+        let year = ChineseYear::new(year).unwrap();
+        let (y, m, d) = seollal_date;
+        assert_eq!(seollal(year), NaiveDate::from_ymd_opt(y, m, d));
+        let (y, m, d) = chunjie_date;
+        assert_eq!(chunjie(year), NaiveDate::from_ymd_opt(y, m, d));
+    }
+
+    let date = NaiveDate::from_ymd_opt(2025, 1, 29).unwrap();
+    let korean_date = KoreanDate::from_gregorian(&date).unwrap();
+    assert_eq!(korean_date, KoreanDate::new(2025, 1, false, 1).unwrap());
+    assert_eq!(korean_date.dangi_year(), 4358);
+
+    for year in [1997, 2027] {
+        let seollal = seollal(ChineseYear::new(year).unwrap()).unwrap();
+        for date in seollal.iter_days().step_by(11).take(40) {
+            let korean_date = KoreanDate::from_gregorian(&date).unwrap();
+            assert_eq!(korean_date.to_gregorian(), date);
+        }
+    }
+}
@@ -1,11 +1,12 @@
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
-use chrono::{Month, Weekday};
+use chrono::{Datelike, Month, NaiveDate, Weekday};
 use Language::*;
 
 use crate::{
     calendar::Calendar,
     chinese_date::{ChineseDate, ChineseDay, ChineseMonth, ChineseYear},
+    korean_date::KoreanDate,
     SolarTerm,
 };
 
@@ -21,6 +22,16 @@ pub const SHENGXIAO_EN: &[&str] = &[
     "Rat", "Ox", "Tiger", "Rabbit", "Dragon", "Snake", "Horse", "Goat", "Monkey", "Rooster", "Dog",
     "Pig",
 ];
+pub const TIANGAN_VI: &[&str] =
+    &["Giáp", "Ất", "Bính", "Đinh", "Mậu", "Kỷ", "Canh", "Tân", "Nhâm", "Quý"];
+pub const DIZHI_VI: &[&str] =
+    &["Tý", "Sửu", "Dần", "Mão", "Thìn", "Tỵ", "Ngọ", "Mùi", "Thân", "Dậu", "Tuất", "Hợi"];
+pub const TIANGAN_KO: &str = "갑을병정무기경신임계";
+pub const DIZHI_KO: &str = "자축인묘진사오미신유술해";
+pub const WUXING: &str = "木火土金水";
+pub const WUXING_EN: &[&str] = &["Wood", "Fire", "Earth", "Metal", "Water"];
+pub const WUXING_VI: &[&str] = &["Mộc", "Hỏa", "Thổ", "Kim", "Thủy"];
+pub const WUXING_KO: &str = "목화토금수";
 pub const NUMBER: &str = "一二三四五六七八九十";
 
 pub fn get_char(s: &str, index: usize) -> Option<char> {
@@ -36,6 +47,9 @@ pub enum Language {
     English,
     ChineseSimplified,
     ChineseTraditional,
+    Vietnamese,
+    Korean,
+    Japanese,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -57,9 +71,39 @@ pub struct TranslateAdapter<'a, T: Translate>(pub &'a T, pub Language);
 #[derive(Clone, Copy, Debug)]
 pub struct Short<'a, T: ShortTranslate>(pub &'a T);
 
+impl Language {
+    pub const ALL: &'static [Language] =
+        &[English, ChineseSimplified, ChineseTraditional, Vietnamese, Korean, Japanese];
+
+    /// Whether the language is written in Chinese characters or Hangul, which are twice as wide
+    /// as Latin letters in a terminal.
+    pub fn is_cjk(self) -> bool {
+        matches!(
+            self,
+            ChineseSimplified | ChineseTraditional | Korean | Japanese
+        )
+    }
+}
+
+/// Abbreviates a Vietnamese phrase to at most 6 characters, as "Lập xuân" to "L.xuân".
+pub(crate) fn abbreviate_vietnamese(s: &str, f: &mut Formatter) -> FmtResult {
+    if s.chars().count() <= 6 {
+        return s.fmt(f);
+    }
+    let abbreviation = match s.split_once(' ') {
+        Some((first, rest)) => format!("{}.{rest}", first.chars().next().unwrap()),
+        None => s.to_string(),
+    };
+    if abbreviation.chars().count() <= 6 {
+        abbreviation.fmt(f)
+    } else {
+        write!(f, "{}.", abbreviation.chars().take(5).collect::<String>())
+    }
+}
+
 pub trait Translate {
     fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult;
-    fn translate_adapter(&self, language: Language) -> TranslateAdapter<Self>
+    fn translate_adapter(&self, language: Language) -> TranslateAdapter<'_, Self>
     where
         Self: Sized,
     {
@@ -75,7 +119,7 @@ pub trait Translate {
 
 pub trait ShortTranslate {
     fn short_translate(&self, language: Language, f: &mut Formatter) -> FmtResult;
-    fn short(&self) -> Short<Self>
+    fn short(&self) -> Short<'_, Self>
     where
         Self: Sized,
     {
@@ -111,30 +155,16 @@ impl<T: ShortTranslate> Translate for Short<'_, T> {
 
 impl Translate for ChineseYear {
     fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
-        let relative_year = self.0 as i16 - 1984;
-        let tiangan = relative_year.rem_euclid(10) as usize;
-        let dizhi = relative_year.rem_euclid(12) as usize;
+        let ganzhi = self.ganzhi();
+        let ganzhi_adapter = ganzhi.translate_adapter(language);
+        let shengxiao = self.shengxiao();
+        let shengxiao = shengxiao.translate_adapter(language);
         match language {
-            English => write!(
-                f,
-                "{}{} Year of the {}",
-                TIANGAN_EN[tiangan], DIZHI_EN[dizhi], SHENGXIAO_EN[dizhi]
-            ),
-            _ => write!(
-                f,
-                "{}{}{}年",
-                get_char(TIANGAN, tiangan).unwrap(),
-                get_char(DIZHI, dizhi).unwrap(),
-                get_char(
-                    if language == ChineseTraditional {
-                        SHENGXIAO_T
-                    } else {
-                        SHENGXIAO_S
-                    },
-                    dizhi,
-                )
-                .unwrap(),
-            ),
+            English => write!(f, "{ganzhi_adapter} Year of the {shengxiao}"),
+            Vietnamese => write!(f, "Năm {ganzhi_adapter}"),
+            Korean => write!(f, "{ganzhi_adapter}년"),
+            Japanese => write!(f, "{ganzhi_adapter}年"),
+            _ => write!(f, "{ganzhi_adapter}{shengxiao}年"),
         }
     }
 }
@@ -143,6 +173,20 @@ impl Translate for ChineseMonth {
     fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
         match language {
             English => write!(f, "{}Month {}", if self.1 { "Leap " } else { "" }, self.0),
+            Vietnamese => {
+                match self.0 {
+                    1 => write!(f, "Tháng Giêng"),
+                    12 => write!(f, "Tháng Chạp"),
+                    month => write!(f, "Tháng {month}"),
+                }?;
+                if self.1 {
+                    write!(f, " nhuận")
+                } else {
+                    Ok(())
+                }
+            }
+            Korean => write!(f, "{}{}월", if self.1 { "윤" } else { "" }, self.0),
+            Japanese => write!(f, "{}{}月", if self.1 { "閏" } else { "" }, self.0),
             _ => write!(
                 f,
                 "{}{}月",
@@ -171,6 +215,8 @@ impl ShortTranslate for ChineseMonth {
     fn short_translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
         if language == English {
             write!(f, "M{}{}", self.0, if self.1 { "+" } else { "" })
+        } else if language == Vietnamese {
+            write!(f, "T{}{}", self.0, if self.1 { "N" } else { "" })
         } else {
             self.translate(language, f)
         }
@@ -193,6 +239,15 @@ impl Translate for ChineseDay {
                 };
                 write!(f, "{}{suffix}", self.0)
             }
+            Vietnamese => {
+                if self.0 <= 10 {
+                    write!(f, "Mùng {}", self.0)
+                } else {
+                    write!(f, "Ngày {}", self.0)
+                }
+            }
+            Korean => write!(f, "{}일", self.0),
+            Japanese => write!(f, "{}日", self.0),
             _ => match self.0 {
                 1..=10 => write!(f, "初{}", get_char(NUMBER, self.0 as usize - 1).unwrap()),
                 11..=19 => write!(f, "十{}", get_char(NUMBER, self.0 as usize - 11).unwrap()),
@@ -209,6 +264,13 @@ impl Translate for MonthTitle {
     fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
         match language {
             English => write!(f, "{} {}", self.month.name(), self.year),
+            Vietnamese => write!(f, "Tháng {} {}", self.month.number_from_month(), self.year),
+            Korean => write!(
+                f,
+                "{}년 {}",
+                self.year,
+                self.month.translate_adapter(Korean)
+            ),
             chinese => write!(
                 f,
                 "{}年 {}",
@@ -216,10 +278,17 @@ impl Translate for MonthTitle {
                 self.month.translate_adapter(chinese)
             ),
         }?;
-        if self.enable_chinese {
-            write!(f, " {}", ChineseYear(self.year).translate_adapter(language))
-        } else {
-            Ok(())
+        let first = NaiveDate::from_ymd_opt(self.year, self.month.number_from_month(), 1);
+        let last = first
+            .and_then(|first| first.with_day(crate::days_of_month(self.year, self.month) as u32));
+        match first
+            .zip(last)
+            .and_then(|(first, last)| ChineseYears::of(first, last))
+        {
+            Some(years) if self.enable_chinese => {
+                write!(f, " {}", years.translate_adapter(language))
+            }
+            _ => Ok(()),
         }
     }
 }
@@ -227,23 +296,70 @@ impl Translate for MonthTitle {
 impl Translate for YearTitle {
     fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
         match language {
-            English => write!(f, "{}", self.year),
+            English | Vietnamese => write!(f, "{}", self.year),
+            Korean => write!(f, "{}년", self.year),
             _ => write!(f, "{}年", self.year),
         }?;
-        if self.enable_chinese {
-            write!(f, " {}", ChineseYear(self.year).translate_adapter(language))
-        } else {
-            Ok(())
+        let first = NaiveDate::from_ymd_opt(self.year, 1, 1);
+        let last = NaiveDate::from_ymd_opt(self.year, 12, 31);
+        match first
+            .zip(last)
+            .and_then(|(first, last)| ChineseYears::of(first, last))
+        {
+            Some(years) if self.enable_chinese => {
+                write!(f, " {}", years.translate_adapter(language))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The Chinese years from one date to another, both written if the span crosses Chunjie.
+struct ChineseYears(ChineseYear, ChineseYear);
+
+impl ChineseYears {
+    fn of(first: NaiveDate, last: NaiveDate) -> Option<Self> {
+        Some(Self(
+            ChineseDate::from_gregorian(&first)?.chinese_year(),
+            ChineseDate::from_gregorian(&last)?.chinese_year(),
+        ))
+    }
+}
+
+impl Translate for ChineseYears {
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        let Self(first, last) = *self;
+        if first == last {
+            return first.translate(language, f);
+        }
+        let ganzhi = [first, last].map(|year| year.ganzhi().translate_to_string(language));
+        let shengxiao = [first, last].map(|year| year.shengxiao().translate_to_string(language));
+        match language {
+            English => write!(
+                f,
+                "{}/{} Year of the {}/{}",
+                ganzhi[0], ganzhi[1], shengxiao[0], shengxiao[1]
+            ),
+            Vietnamese => write!(f, "Năm {}/{}", ganzhi[0], ganzhi[1]),
+            Korean => write!(f, "{}/{}년", ganzhi[0], ganzhi[1]),
+            Japanese => write!(f, "{}/{}年", ganzhi[0], ganzhi[1]),
+            _ => write!(
+                f,
+                "{}{}/{}{}年",
+                ganzhi[0], shengxiao[0], ganzhi[1], shengxiao[1]
+            ),
         }
     }
 }
 
 impl Translate for Month {
     fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
-        if language == English {
-            write!(f, "{}", self.name())
-        } else {
-            write!(f, "{}月", self.short().translate_adapter(language))
+        match language {
+            English => write!(f, "{}", self.name()),
+            Vietnamese => write!(f, "Tháng {}", self.number_from_month()),
+            Korean => write!(f, "{}월", self.number_from_month()),
+            Japanese => write!(f, "{}月", self.number_from_month()),
+            _ => write!(f, "{}月", self.short().translate_adapter(language)),
         }
     }
 }
@@ -266,6 +382,18 @@ impl StaticTranslate for Month {
         let number = self.number_from_month();
         match language {
             English => self.name(),
+            Vietnamese => [
+                "Th1", "Th2", "Th3", "Th4", "Th5", "Th6", "Th7", "Th8", "Th9", "Th10", "Th11",
+                "Th12",
+            ][number as usize - 1],
+            Korean => [
+                "1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월",
+                "12월",
+            ][number as usize - 1],
+            Japanese => [
+                "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月",
+                "12月",
+            ][number as usize - 1],
             _ => match number {
                 1..=10 => get_char_as_str("一二三四五六七八九十", number as usize - 1).unwrap(),
                 11 => "十一",
@@ -285,6 +413,14 @@ impl Translate for Weekday {
                 ["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"]
                     [self.num_days_from_sunday() as usize],
             ),
+            Vietnamese => write!(
+                f,
+                "{}",
+                ["Chủ nhật", "Thứ hai", "Thứ ba", "Thứ tư", "Thứ năm", "Thứ sáu", "Thứ bảy"]
+                    [self.num_days_from_sunday() as usize],
+            ),
+            Korean => write!(f, "{}요일", self.short().translate_adapter(language)),
+            Japanese => write!(f, "{}曜日", self.short().translate_adapter(language)),
             _ => write!(f, "星期{}", self.short().translate_adapter(language)),
         }
     }
@@ -298,11 +434,14 @@ impl ShortTranslate for Weekday {
 
 impl StaticTranslate for Weekday {
     fn static_translate(&self, language: Language) -> &'static str {
-        let index = self.num_days_from_sunday() as usize * 3;
-        &(match language {
-            English => "SunMonTueWedThuFriSat",
-            _ => "日一二三四五六",
-        })[index..index + 3]
+        let index = self.num_days_from_sunday() as usize;
+        match language {
+            English => &"SunMonTueWedThuFriSat"[index * 3..index * 3 + 3],
+            Vietnamese => ["CN", "T2", "T3", "T4", "T5", "T6", "T7"][index],
+            Korean => get_char_as_str("일월화수목금토", index).unwrap(),
+            Japanese => get_char_as_str("日月火水木金土", index).unwrap(),
+            _ => get_char_as_str("日一二三四五六", index).unwrap(),
+        }
     }
 }
 
@@ -316,6 +455,10 @@ impl Translate for ChineseDate {
         let day_adapter = day.translate_adapter(language);
         if language == English {
             write!(f, "{day_adapter}, {month_adapter}, {year_adapter}")
+        } else if language == Vietnamese {
+            write!(f, "{day_adapter} {month_adapter} {year_adapter}")
+        } else if language == Korean {
+            write!(f, "{year_adapter} {month_adapter} {day_adapter}")
         } else {
             write!(f, "{year_adapter}{month_adapter}{day_adapter}")
         }
@@ -324,7 +467,14 @@ impl Translate for ChineseDate {
 
 impl ShortTranslate for ChineseDate {
     fn short_translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
-        if self.chinese_day().get() == 1 {
+        if language == Vietnamese {
+            // Written as day/month on the first day of a month
+            write!(f, "{}", self.day())?;
+            if self.day() == 1 {
+                write!(f, "/{}{}", self.month(), if self.leap() { "N" } else { "" })?;
+            }
+            Ok(())
+        } else if self.chinese_day().get() == 1 {
             self.chinese_month().short().translate(language, f)
         } else {
             self.chinese_day().translate(language, f)
@@ -332,6 +482,28 @@ impl ShortTranslate for ChineseDate {
     }
 }
 
+impl Translate for KoreanDate {
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        let (month, day) = (self.0.chinese_month(), self.0.chinese_day());
+        let month = month.translate_adapter(language);
+        let day = day.translate_adapter(language);
+        let dangi_year = self.dangi_year();
+        match language {
+            English => write!(f, "{day}, {month}, Dangi {dangi_year}"),
+            ChineseSimplified => write!(f, "檀纪{dangi_year}年{month}{day}"),
+            ChineseTraditional | Japanese => write!(f, "檀紀{dangi_year}年{month}{day}"),
+            Vietnamese => write!(f, "{day} {month} năm Dangi {dangi_year}"),
+            Korean => write!(f, "단기 {dangi_year}년 {month} {day}"),
+        }
+    }
+}
+
+impl ShortTranslate for KoreanDate {
+    fn short_translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        self.0.short_translate(language, f)
+    }
+}
+
 impl StaticTranslate for SolarTerm {
     fn static_translate(&self, language: Language) -> &'static str {
         let ordinal = self.as_ordinal() as usize;
@@ -362,11 +534,40 @@ impl StaticTranslate for SolarTerm {
                 "Daxue",
                 "Dongzhi",
             ][ordinal]
+        } else if language == Vietnamese {
+            [
+                "Tiểu hàn",
+                "Đại hàn",
+                "Lập xuân",
+                "Vũ thủy",
+                "Kinh trập",
+                "Xuân phân",
+                "Thanh minh",
+                "Cốc vũ",
+                "Lập hạ",
+                "Tiểu mãn",
+                "Mang chủng",
+                "Hạ chí",
+                "Tiểu thử",
+                "Đại thử",
+                "Lập thu",
+                "Xử thử",
+                "Bạch lộ",
+                "Thu phân",
+                "Hàn lộ",
+                "Sương giáng",
+                "Lập đông",
+                "Tiểu tuyết",
+                "Đại tuyết",
+                "Đông chí",
+            ][ordinal]
         } else {
             &(match language {
                 ChineseSimplified => "小寒大寒立春雨水惊蛰春分清明谷雨立夏小满芒种夏至小暑大暑立秋处暑白露秋分寒露霜降立冬小雪大雪冬至",
                 ChineseTraditional => "小寒大寒立春雨水驚蟄春分清明穀雨立夏小滿芒種夏至小暑大暑立秋處暑白露秋分寒露霜降立冬小雪大雪冬至",
-                English => unreachable!(),
+                Korean => "소한대한입춘우수경칩춘분청명곡우입하소만망종하지소서대서입추처서백로추분한로상강입동소설대설동지",
+                Japanese => "小寒大寒立春雨水啓蟄春分清明穀雨立夏小満芒種夏至小暑大暑立秋処暑白露秋分寒露霜降立冬小雪大雪冬至",
+                English | Vietnamese => unreachable!(),
             })[ordinal * 6..ordinal * 6 + 6]
         }
     }
@@ -382,8 +583,37 @@ impl ShortTranslate for SolarTerm {
     fn short_translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
         if language == English && self.static_translate(English).len() > 6 {
             write!(f, "{}.", &self.static_translate(English)[..5])
+        } else if language == Vietnamese {
+            abbreviate_vietnamese(self.static_translate(language), f)
         } else {
             self.translate(language, f)
         }
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test() {
+    use chrono::Month::{February, January, March};
+
+    let title = |month, language| {
+        MonthTitle {
+            year: 2024,
+            month,
+            enable_chinese: true,
+        }
+        .translate_to_string(language)
+    };
+    // Chunjie of 2024 is on 02-10
+    assert!(title(January, English).ends_with(" Guimao Year of the Rabbit"));
+    assert!(title(February, English).ends_with(" Guimao/Jiachen Year of the Rabbit/Dragon"));
+    assert!(title(February, ChineseSimplified).ends_with(" 癸卯兔/甲辰龙年"));
+    assert!(title(March, English).ends_with(" Jiachen Year of the Dragon"));
+    let title = YearTitle {
+        year: 2024,
+        enable_chinese: true,
+    };
+    assert!(title
+        .translate_to_string(English)
+        .ends_with(" Guimao/Jiachen Year of the Rabbit/Dragon"));
+}
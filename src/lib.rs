@@ -17,15 +17,31 @@ fn test() {
 ```
 */
 
+pub mod age;
+pub mod astronomy;
+pub mod birthday;
 pub mod calendar;
 pub mod chinese_date;
 #[cfg(feature = "cli")]
 pub mod cli_calendar;
 pub mod data;
+pub mod events;
 pub mod festivals;
+pub mod four_pillars;
+pub mod ganzhi;
+pub mod holidays;
 pub mod iter;
+pub mod japanese_date;
+pub mod korean_date;
 pub mod language;
+pub mod moon_phase;
+pub mod pentad;
+pub mod shengxiao;
+pub mod shichen;
 pub mod solar_term;
+pub mod vietnamese_date;
+pub mod wuxing;
+pub mod zajie;
 
 pub use chinese_date::ChineseDate;
 pub use solar_term::SolarTerm;
@@ -70,3 +86,9 @@ pub fn days_of_month(year: i32, month: chrono::Month) -> u8 {
         December => 31,
     }
 }
+
+/// A Gregorian date known to be valid, for tests.
+#[cfg(test)]
+pub(crate) fn date(year: i32, month: u32, day: u32) -> chrono::NaiveDate {
+    chrono::NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
@@ -1,12 +1,18 @@
 use std::{io::IsTerminal, process::exit};
 
 use chrono::{Datelike, Month};
-use clap::{arg, value_parser, Command};
+use clap::{arg, value_parser, ArgAction, Command};
 use nongli::{
-    calendar::{Calendar, Options},
+    birthday::Birthday,
+    calendar::{Calendar, Options, Variant},
+    chinese_date::days_of_chinese_month,
     cli_calendar::{ListCalendar, MonthCalendar, TripleCalendar, YearCalendar},
+    holidays::{Region, Regions},
     iter::Months,
+    japanese_date::JapaneseDate,
+    korean_date::KoreanDate,
     language::{Language::*, Translate},
+    vietnamese_date::VietnameseDate,
     ChineseDate,
 };
 
@@ -18,6 +24,25 @@ fn cmd() -> Command {
                 .value_parser(["always", "auto", "never"])
                 .default_missing_value("always"),
         )
+        .arg(
+            arg!(--variant <variant> "Lunisolar calendar to show").value_parser([
+                "chinese",
+                "vietnamese",
+                "korean",
+                "japanese",
+            ]),
+        )
+        .arg(arg!(-r --rokuyo "Show rokuyō"))
+        .arg(arg!(-g --ganzhi "Show the ganzhi of days in the list"))
+        .arg(arg!(--moon "Show the principal phases of the moon"))
+        .arg(arg!(--periods "Show the dog days and the nine-nines"))
+        .arg(arg!(--pentads "Show the beginnings of the 72 pentads in the list"))
+        .arg(
+            arg!(--holidays <regions> "Mark public holidays and adjusted working days")
+                .value_parser(["mainland", "hongkong", "macau", "taiwan"])
+                .value_delimiter(',')
+                .action(ArgAction::Append),
+        )
         .arg(arg!(-M --"start-on-monday" "Start on monday"))
         .arg(arg!(-n --"no-highlight-today" "Don't highlight today"))
         .arg(
@@ -47,6 +72,41 @@ fn cmd() -> Command {
         )
         .arg(arg!(-w --week "Show week numbers"))
         .arg(arg!(-t --today "Show today in Chinese calendar"))
+        .subcommand(
+            Command::new("birthday")
+                .about("Show the next lunar birthdays")
+                .arg(
+                    arg!(<date> "Date of birth in Chinese calendar, as 1990-08-15, or 2023-L02-15 in a leap month")
+                        .value_parser(parse_chinese_date),
+                )
+                .arg(
+                    arg!(-n --count <count> "Number of birthdays")
+                        .value_parser(value_parser!(usize))
+                        .default_value("5"),
+                ),
+        )
+}
+
+fn parse_chinese_date(string: &str) -> Result<ChineseDate, String> {
+    let mut parts = string.splitn(3, '-');
+    let (Some(year), Some(month), Some(day)) = (parts.next(), parts.next(), parts.next()) else {
+        return Err("expected a date as 1990-08-15".to_string());
+    };
+    let (month, leap) = match month.strip_prefix('L') {
+        Some(month) => (month, true),
+        None => (month, false),
+    };
+    year.parse()
+        .ok()
+        .zip(month.parse().ok())
+        .zip(day.parse().ok())
+        .and_then(|((year, month), day)| {
+            let date = ChineseDate::new(year, month, leap, day)?;
+            days_of_chinese_month(date.chinese_year(), date.chinese_month())
+                .is_some_and(|days| day <= days)
+                .then_some(date)
+        })
+        .ok_or_else(|| "no such date in Chinese calendar".to_string())
 }
 
 #[cfg(test)]
@@ -72,13 +132,16 @@ fn main() {
                     ChineseSimplified
                 }
             }
-            None => {
-                if s == "zh" {
-                    ChineseSimplified
-                } else {
-                    English
-                }
-            }
+            Some(("vi", _)) => Vietnamese,
+            Some(("ko", _)) => Korean,
+            Some(("ja", _)) => Japanese,
+            None => match s.as_str() {
+                "zh" => ChineseSimplified,
+                "vi" => Vietnamese,
+                "ko" => Korean,
+                "ja" => Japanese,
+                _ => English,
+            },
             _ => English,
         },
         Err(_) => English,
@@ -93,6 +156,18 @@ fn main() {
         },
         _ => language != English,
     };
+    let variant = match matches.get_one::<String>("variant").map(String::as_str) {
+        Some("vietnamese") => Variant::Vietnamese,
+        Some("korean") => Variant::Korean,
+        Some("japanese") => Variant::Japanese,
+        Some(_) => Variant::Chinese,
+        None => match language {
+            Vietnamese => Variant::Vietnamese,
+            Korean => Variant::Korean,
+            Japanese => Variant::Japanese,
+            _ => Variant::Chinese,
+        },
+    };
     let color = match matches.get_one::<String>("color") {
         Some(s) => match s.as_str() {
             "always" => true,
@@ -107,23 +182,69 @@ fn main() {
     let list = matches.get_flag("list");
     let week_number = matches.get_flag("week");
     let show_today = matches.get_flag("today");
+    let rokuyo = matches.get_flag("rokuyo");
+    let day_ganzhi = matches.get_flag("ganzhi");
+    let moon_phase = matches.get_flag("moon");
+    let periods = matches.get_flag("periods");
+    let pentads = matches.get_flag("pentads");
+    let holidays = matches
+        .get_many::<String>("holidays")
+        .into_iter()
+        .flatten()
+        .map(|region| match region.as_str() {
+            "hongkong" => Region::HongKong,
+            "macau" => Region::Macau,
+            "taiwan" => Region::Taiwan,
+            _ => Region::Mainland,
+        })
+        .collect::<Regions>()
+        .into();
 
     let today = std::env::var("TODAY")
         .ok()
         .and_then(|string| chrono::NaiveDate::parse_from_str(&string, "%Y-%m-%d").ok())
         .unwrap_or_else(|| chrono::Local::now().date_naive());
 
+    if let Some(matches) = matches.subcommand_matches("birthday") {
+        let birthday = Birthday::new(*matches.get_one::<ChineseDate>("date").unwrap());
+        let count = *matches.get_one::<usize>("count").unwrap();
+        for date in birthday.occurrences_from(today).take(count) {
+            let chinese_date = ChineseDate::from_gregorian(&date).unwrap();
+            println!("{date}  {}", chinese_date.translate_adapter(language));
+        }
+        return;
+    }
+
     let options = Options {
         language,
         enable_chinese,
+        variant,
+        rokuyo,
+        day_ganzhi,
+        moon_phase,
+        periods,
+        pentads,
+        events: None,
+        holidays,
         start_on_monday,
         color,
         week_number,
     };
 
     if show_today {
-        if let Some(chinese_date) = ChineseDate::from_gregorian(&today) {
-            println!("{}", chinese_date.translate_adapter(language));
+        let translation =
+            match variant {
+                Variant::Chinese => ChineseDate::from_gregorian(&today)
+                    .map(|date| date.translate_to_string(language)),
+                Variant::Vietnamese => VietnameseDate::from_gregorian(&today)
+                    .map(|date| date.0.translate_to_string(language)),
+                Variant::Korean => KoreanDate::from_gregorian(&today)
+                    .map(|date| date.translate_to_string(language)),
+                Variant::Japanese => JapaneseDate::from_gregorian(&today)
+                    .map(|date| date.0.translate_to_string(language)),
+            };
+        if let Some(translation) = translation {
+            println!("{translation}");
         } else {
             println!("Today is out of the Chinese calendar range");
         }
@@ -149,10 +270,10 @@ fn main() {
             };
             if list {
                 if triple {
-                    if let Some(pred) = calendar.pred() {
+                    if let Some(pred) = calendar.clone().pred() {
                         println!("{}", ListCalendar(pred));
                     }
-                    println!("{}", ListCalendar(calendar));
+                    println!("{}", ListCalendar(calendar.clone()));
                     if let Some(succ) = calendar.succ() {
                         println!("{}", ListCalendar(succ));
                     }
@@ -175,8 +296,13 @@ fn main() {
                     print!(
                         "{}",
                         ListCalendar(
-                            Calendar::new(year, month, highlight_today.then_some(today), options)
-                                .unwrap()
+                            Calendar::new(
+                                year,
+                                month,
+                                highlight_today.then_some(today),
+                                options.clone()
+                            )
+                            .unwrap()
                         )
                     );
                 }
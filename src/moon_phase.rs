@@ -0,0 +1,184 @@
+//! The principal phases of the moon and the phase at any instant.
+
+use chrono::{DateTime, Datelike, NaiveDate, TimeZone, Utc};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{
+    astronomy::{datetime, julian_day, julian_day_of, lunation, moon_elongation, moon_phase},
+    chinese_date::BEIJING_OFFSET,
+    language::{Language, ShortTranslate, StaticTranslate, Translate},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MoonPhase {
+    NewMoon,
+    FirstQuarter,
+    FullMoon,
+    LastQuarter,
+}
+
+impl MoonPhase {
+    pub fn as_ordinal(self) -> u8 {
+        self as u8
+    }
+    pub fn from_ordinal(ordinal: u8) -> Option<Self> {
+        use MoonPhase::*;
+        match ordinal {
+            0 => Some(NewMoon),
+            1 => Some(FirstQuarter),
+            2 => Some(FullMoon),
+            3 => Some(LastQuarter),
+            _ => None,
+        }
+    }
+    /// The instant of the phase in a lunation, counted from the new moon of 2000-01-06.
+    pub fn instant(self, lunation: i32) -> Option<DateTime<Utc>> {
+        datetime(moon_phase(lunation, self.as_ordinal()))
+    }
+    /// The principal phase beginning on a date in Beijing time, if any.
+    pub fn from_date(date: &impl Datelike) -> Option<Self> {
+        let date = NaiveDate::from_ymd_opt(date.year(), date.month(), date.day())?;
+        Self::from_date_at(date, BEIJING_OFFSET)
+    }
+    /// The principal phase beginning on a date at a UTC offset in seconds, if any.
+    pub(crate) fn from_date_at(date: NaiveDate, offset: i32) -> Option<Self> {
+        let start = julian_day(date) - offset as f64 / 86400.0;
+        let end = start + 1.0;
+        // The principal phases are at least 6 days apart, so at most one begins on a day
+        let lunation = lunation(start);
+        (lunation - 1..=lunation).find_map(|lunation| {
+            (0..4).find_map(|phase| {
+                let jd = moon_phase(lunation, phase);
+                (start..end)
+                    .contains(&jd)
+                    .then(|| Self::from_ordinal(phase).unwrap())
+            })
+        })
+    }
+    pub fn symbol(self) -> char {
+        match self {
+            MoonPhase::NewMoon => '🌑',
+            MoonPhase::FirstQuarter => '🌓',
+            MoonPhase::FullMoon => '🌕',
+            MoonPhase::LastQuarter => '🌗',
+        }
+    }
+}
+
+/// Fraction of the lunation elapsed at an instant, from 0 at the new moon through 0.5 at the full
+/// moon to 1.
+///
+/// The fraction is the elongation of the moon east of the sun in turns, so it grows a little
+/// faster near perigee than near apogee.
+pub fn phase_fraction<Tz: TimeZone>(datetime: &DateTime<Tz>) -> f64 {
+    moon_elongation(julian_day_of(datetime.with_timezone(&Utc))) / 360.0
+}
+
+/// Illuminated fraction of the disk of the moon at an instant, from 0 at the new moon to 1 at the
+/// full moon, from the elongation and neglecting the latitude and distance of the moon.
+pub fn illuminated_fraction<Tz: TimeZone>(datetime: &DateTime<Tz>) -> f64 {
+    (1.0 - (phase_fraction(datetime) * std::f64::consts::TAU).cos()) / 2.0
+}
+
+impl StaticTranslate for MoonPhase {
+    fn static_translate(&self, language: Language) -> &'static str {
+        use Language::*;
+        use MoonPhase::*;
+        match language {
+            English => match self {
+                NewMoon => "New moon",
+                FirstQuarter => "First quarter",
+                FullMoon => "Full moon",
+                LastQuarter => "Last quarter",
+            },
+            ChineseSimplified | ChineseTraditional => match self {
+                NewMoon => "朔",
+                FirstQuarter => "上弦",
+                FullMoon => "望",
+                LastQuarter => "下弦",
+            },
+            Vietnamese => match self {
+                NewMoon => "Sóc",
+                FirstQuarter => "Thượng huyền",
+                FullMoon => "Vọng",
+                LastQuarter => "Hạ huyền",
+            },
+            Korean => match self {
+                NewMoon => "삭",
+                FirstQuarter => "상현",
+                FullMoon => "망",
+                LastQuarter => "하현",
+            },
+            Japanese => match self {
+                NewMoon => "新月",
+                FirstQuarter => "上弦",
+                FullMoon => "満月",
+                LastQuarter => "下弦",
+            },
+        }
+    }
+}
+
+impl Translate for MoonPhase {
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        self.static_translate(language).fmt(f)
+    }
+}
+
+impl ShortTranslate for MoonPhase {
+    /// The symbol of the phase, which is the same in every language.
+    fn short_translate(&self, _language: Language, f: &mut Formatter) -> FmtResult {
+        self.symbol().fmt(f)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    use chrono::FixedOffset;
+
+    let lunation = lunation(julian_day(NaiveDate::from_ymd_opt(2024, 1, 11).unwrap()));
+    for (phase, (day, hour, minute)) in [
+        (MoonPhase::NewMoon, (11, 11, 57)),
+        (MoonPhase::FirstQuarter, (18, 3, 52)),
+        (MoonPhase::FullMoon, (25, 17, 54)),
+        (MoonPhase::LastQuarter, (33, 23, 18)),
+    ] {
+        let expected = Utc.with_ymd_and_hms(2024, 1, 1, hour, minute, 0).unwrap()
+            + chrono::TimeDelta::days(day - 1);
+        let instant = phase.instant(lunation).unwrap();
+        assert!(
+            (instant - expected).num_seconds().abs() < 120,
+            "{phase:?} {instant}"
+        );
+    }
+
+    // The full moon of the 8th month of 2024 is on the 16th day
+    let date = NaiveDate::from_ymd_opt(2024, 9, 18).unwrap();
+    assert_eq!(MoonPhase::from_date(&date), Some(MoonPhase::FullMoon));
+    assert_eq!(MoonPhase::from_date(&date.pred_opt().unwrap()), None);
+    // The first quarter of 2024-01-18 at 03:52 UTC is still on 01-17 at UTC-5
+    let date = NaiveDate::from_ymd_opt(2024, 1, 17).unwrap();
+    assert_eq!(
+        MoonPhase::from_date_at(date, -5 * 3600),
+        Some(MoonPhase::FirstQuarter)
+    );
+
+    let full_moon = MoonPhase::FullMoon.instant(lunation).unwrap();
+    // The instants of the phases are good to a minute, in which the elongation changes by 3e-5 turns
+    for phase in
+        [MoonPhase::NewMoon, MoonPhase::FirstQuarter, MoonPhase::FullMoon, MoonPhase::LastQuarter]
+    {
+        let fraction = phase_fraction(&phase.instant(lunation).unwrap());
+        let expected = phase.as_ordinal() as f64 / 4.0;
+        assert!(
+            ((fraction - expected + 0.5).rem_euclid(1.0) - 0.5).abs() < 3e-5,
+            "{phase:?} {fraction}"
+        );
+    }
+    assert!((illuminated_fraction(&full_moon) - 1.0).abs() < 1e-6);
+    let beijing = FixedOffset::east_opt(BEIJING_OFFSET).unwrap();
+    let new_moon = MoonPhase::NewMoon.instant(lunation).unwrap();
+    let fraction = phase_fraction(&(new_moon + chrono::TimeDelta::days(3)).with_timezone(&beijing));
+    assert!(fraction > 0.1 && fraction < 0.125);
+}
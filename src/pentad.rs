@@ -0,0 +1,404 @@
+//! The 72 pentads (七十二候), three to a solar term, each beginning when the apparent longitude of
+//! the sun advances by 5°.
+
+use chrono::{Datelike, FixedOffset, NaiveDate};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{
+    astronomy::{datetime, julian_day, sun_longitude_instant},
+    chinese_date::{BEIJING_OFFSET, YEARS},
+    language::{Language, ShortTranslate, StaticTranslate, Translate},
+    SolarTerm,
+};
+
+/// One of the three pentads of a solar term, numbered from 0.
+///
+/// Pentads have no established Vietnamese names, so they are written in English in Vietnamese,
+/// with the Vietnamese names of solar terms in their short forms.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pentad {
+    solar_term: SolarTerm,
+    index: u8,
+}
+
+const PENTADS_S: &[&str] = &[
+    "雁北乡",
+    "鹊始巢",
+    "雉始雊",
+    "鸡始乳",
+    "征鸟厉疾",
+    "水泽腹坚",
+    "东风解冻",
+    "蛰虫始振",
+    "鱼陟负冰",
+    "獭祭鱼",
+    "候雁北",
+    "草木萌动",
+    "桃始华",
+    "仓庚鸣",
+    "鹰化为鸠",
+    "玄鸟至",
+    "雷乃发声",
+    "始电",
+    "桐始华",
+    "田鼠化为鴽",
+    "虹始见",
+    "萍始生",
+    "鸣鸠拂其羽",
+    "戴胜降于桑",
+    "蝼蝈鸣",
+    "蚯蚓出",
+    "王瓜生",
+    "苦菜秀",
+    "靡草死",
+    "麦秋至",
+    "螳螂生",
+    "鵙始鸣",
+    "反舌无声",
+    "鹿角解",
+    "蜩始鸣",
+    "半夏生",
+    "温风至",
+    "蟋蟀居壁",
+    "鹰始挚",
+    "腐草为萤",
+    "土润溽暑",
+    "大雨时行",
+    "凉风至",
+    "白露降",
+    "寒蝉鸣",
+    "鹰乃祭鸟",
+    "天地始肃",
+    "禾乃登",
+    "鸿雁来",
+    "玄鸟归",
+    "群鸟养羞",
+    "雷始收声",
+    "蛰虫坯户",
+    "水始涸",
+    "鸿雁来宾",
+    "雀入大水为蛤",
+    "菊有黄华",
+    "豺乃祭兽",
+    "草木黄落",
+    "蛰虫咸俯",
+    "水始冰",
+    "地始冻",
+    "雉入大水为蜃",
+    "虹藏不见",
+    "天气上升地气下降",
+    "闭塞而成冬",
+    "鹖鴠不鸣",
+    "虎始交",
+    "荔挺出",
+    "蚯蚓结",
+    "麋角解",
+    "水泉动",
+];
+const PENTADS_T: &[&str] = &[
+    "雁北鄉",
+    "鵲始巢",
+    "雉始雊",
+    "雞始乳",
+    "征鳥厲疾",
+    "水澤腹堅",
+    "東風解凍",
+    "蟄蟲始振",
+    "魚陟負冰",
+    "獺祭魚",
+    "候雁北",
+    "草木萌動",
+    "桃始華",
+    "倉庚鳴",
+    "鷹化為鳩",
+    "玄鳥至",
+    "雷乃發聲",
+    "始電",
+    "桐始華",
+    "田鼠化為鴽",
+    "虹始見",
+    "萍始生",
+    "鳴鳩拂其羽",
+    "戴勝降于桑",
+    "螻蟈鳴",
+    "蚯蚓出",
+    "王瓜生",
+    "苦菜秀",
+    "靡草死",
+    "麥秋至",
+    "螳螂生",
+    "鵙始鳴",
+    "反舌無聲",
+    "鹿角解",
+    "蜩始鳴",
+    "半夏生",
+    "溫風至",
+    "蟋蟀居壁",
+    "鷹始摯",
+    "腐草為螢",
+    "土潤溽暑",
+    "大雨時行",
+    "涼風至",
+    "白露降",
+    "寒蟬鳴",
+    "鷹乃祭鳥",
+    "天地始肅",
+    "禾乃登",
+    "鴻雁來",
+    "玄鳥歸",
+    "群鳥養羞",
+    "雷始收聲",
+    "蟄蟲坯戶",
+    "水始涸",
+    "鴻雁來賓",
+    "雀入大水為蛤",
+    "菊有黃華",
+    "豺乃祭獸",
+    "草木黃落",
+    "蟄蟲咸俯",
+    "水始冰",
+    "地始凍",
+    "雉入大水為蜃",
+    "虹藏不見",
+    "天氣上升地氣下降",
+    "閉塞而成冬",
+    "鶡鴠不鳴",
+    "虎始交",
+    "荔挺出",
+    "蚯蚓結",
+    "麋角解",
+    "水泉動",
+];
+const PENTADS_EN: &[&str] = &[
+    "Geese head north",
+    "Magpies begin to nest",
+    "Pheasants begin to call",
+    "Hens begin to lay",
+    "Birds of prey fly swift and fierce",
+    "Waters freeze solid",
+    "East wind thaws the ice",
+    "Hibernating insects stir",
+    "Fish rise to the ice",
+    "Otters offer fish",
+    "Wild geese fly north",
+    "Plants sprout",
+    "Peach trees blossom",
+    "Orioles sing",
+    "Hawks become doves",
+    "Swallows arrive",
+    "Thunder sounds",
+    "Lightning begins",
+    "Paulownias blossom",
+    "Moles become quails",
+    "Rainbows appear",
+    "Duckweed grows",
+    "Turtledoves preen",
+    "Hoopoes alight on mulberries",
+    "Crickets chirp",
+    "Earthworms emerge",
+    "Snake gourds grow",
+    "Sow thistles flower",
+    "Delicate grasses wither",
+    "Wheat ripens",
+    "Mantises hatch",
+    "Shrikes sing",
+    "Mockingbirds fall silent",
+    "Deer shed antlers",
+    "Cicadas sing",
+    "Crow-dippers grow",
+    "Warm winds arrive",
+    "Crickets shelter by walls",
+    "Young hawks learn to fly",
+    "Rotting grass becomes fireflies",
+    "Soil is damp and air humid",
+    "Heavy rains fall",
+    "Cool winds arrive",
+    "White dew descends",
+    "Autumn cicadas sing",
+    "Hawks offer birds",
+    "Heaven and earth grow solemn",
+    "Grain ripens",
+    "Wild geese come",
+    "Swallows return",
+    "Birds store food",
+    "Thunder falls silent",
+    "Insects seal their burrows",
+    "Waters dry up",
+    "Wild geese come as guests",
+    "Sparrows enter the sea as clams",
+    "Chrysanthemums bloom yellow",
+    "Jackals offer beasts",
+    "Leaves turn yellow and fall",
+    "Insects lie dormant",
+    "Water begins to freeze",
+    "Earth begins to freeze",
+    "Pheasants enter the sea as clams",
+    "Rainbows hide",
+    "Heaven's qi rises and earth's descends",
+    "All is closed and winter comes",
+    "Birds of night fall silent",
+    "Tigers begin to mate",
+    "Irises sprout",
+    "Earthworms curl up",
+    "Elk shed antlers",
+    "Springs stir",
+];
+
+impl Pentad {
+    pub fn new(solar_term: SolarTerm, index: u8) -> Option<Self> {
+        (index < 3).then_some(Self { solar_term, index })
+    }
+    pub fn solar_term(self) -> SolarTerm {
+        self.solar_term
+    }
+    pub fn index(self) -> u8 {
+        self.index
+    }
+    /// Ordinal from 0 for the first pentad of Xiaohan to 71.
+    pub fn as_ordinal(self) -> u8 {
+        self.solar_term.as_ordinal() * 3 + self.index
+    }
+    pub fn from_ordinal(ordinal: u8) -> Option<Self> {
+        Self::new(SolarTerm::from_ordinal(ordinal / 3)?, ordinal % 3)
+    }
+    /// Apparent ecliptic longitude of the sun at the beginning of the pentad, in degrees.
+    pub fn longitude(self) -> f64 {
+        (self.solar_term.longitude() + self.index as f64 * 5.0) % 360.0
+    }
+    /// Date of the pentad in the Gregorian year of its solar term, in Beijing time.
+    pub fn date(self, year: i32) -> Option<NaiveDate> {
+        self.date_at(year, BEIJING_OFFSET)
+    }
+    fn date_at(self, year: i32, offset: i32) -> Option<NaiveDate> {
+        if self.index == 0 {
+            return self.solar_term.date_at(year, offset);
+        }
+        if !YEARS.contains(&year) {
+            return None;
+        }
+        let guess =
+            julian_day(NaiveDate::from_ymd_opt(year, 1, 6)?) + self.as_ordinal() as f64 * 5.07;
+        let tz = FixedOffset::east_opt(offset)?;
+        datetime(sun_longitude_instant(self.longitude(), guess))
+            .map(|instant| instant.with_timezone(&tz).date_naive())
+    }
+    /// The pentads which may begin in the month of a date, with the one in effect at its
+    /// beginning, and the years of their solar terms.
+    fn candidates(date: NaiveDate) -> impl Iterator<Item = (i32, Self)> {
+        // The last two pentads of the previous month may still be in effect or begin in this month
+        let first = date.month0() as i32 * 6 - 2;
+        (first..first + 9).map(move |ordinal| {
+            let year = date.year() + ordinal.div_euclid(72);
+            (
+                year,
+                Self::from_ordinal(ordinal.rem_euclid(72) as u8).unwrap(),
+            )
+        })
+    }
+    /// The pentad beginning on a date in Beijing time, if any.
+    pub fn from_date(date: &impl Datelike) -> Option<Self> {
+        let date = NaiveDate::from_ymd_opt(date.year(), date.month(), date.day())?;
+        Self::from_date_at(date, BEIJING_OFFSET)
+    }
+    /// The pentad beginning on a date at a UTC offset in seconds, if any.
+    pub(crate) fn from_date_at(date: NaiveDate, offset: i32) -> Option<Self> {
+        Self::candidates(date)
+            .find(|(year, pentad)| pentad.date_at(*year, offset) == Some(date))
+            .map(|(_, pentad)| pentad)
+    }
+    /// The pentad a date in Beijing time falls in.
+    pub fn containing(date: &impl Datelike) -> Option<Self> {
+        let date = NaiveDate::from_ymd_opt(date.year(), date.month(), date.day())?;
+        Self::candidates(date)
+            .filter(|(year, pentad)| pentad.date(*year).is_some_and(|start| start <= date))
+            .last()
+            .map(|(_, pentad)| pentad)
+    }
+}
+
+impl StaticTranslate for Pentad {
+    /// Korean and Japanese use the traditional Chinese names, and Vietnamese the English ones.
+    fn static_translate(&self, language: Language) -> &'static str {
+        let ordinal = self.as_ordinal() as usize;
+        match language {
+            Language::English | Language::Vietnamese => PENTADS_EN[ordinal],
+            Language::ChineseSimplified => PENTADS_S[ordinal],
+            _ => PENTADS_T[ordinal],
+        }
+    }
+}
+
+impl Translate for Pentad {
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        self.static_translate(language).fmt(f)
+    }
+}
+
+impl ShortTranslate for Pentad {
+    /// The solar term and the number of the pentad, as "立春二候".
+    fn short_translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        let solar_term = self.solar_term.static_translate(language);
+        let index = self.index as usize;
+        match language {
+            Language::English | Language::Vietnamese => write!(f, "{solar_term} {}", index + 1),
+            Language::Korean => write!(f, "{solar_term} {}후", ["초", "이", "삼"][index]),
+            _ => write!(f, "{solar_term}{}候", ["初", "二", "三"][index]),
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    use Language::*;
+
+    for ordinal in 0..72 {
+        let pentad = Pentad::from_ordinal(ordinal).unwrap();
+        assert_eq!(pentad.as_ordinal(), ordinal);
+        assert!(!PENTADS_T[ordinal as usize].is_empty());
+    }
+    assert_eq!(PENTADS_S.len(), 72);
+    assert_eq!(PENTADS_T.len(), 72);
+    assert_eq!(PENTADS_EN.len(), 72);
+
+    let lichun = Pentad::new(SolarTerm::Lichun, 0).unwrap();
+    assert_eq!(lichun.translate_to_string(ChineseSimplified), "东风解冻");
+    assert_eq!(lichun.translate_to_string(ChineseTraditional), "東風解凍");
+    assert_eq!(
+        lichun.translate_to_string(English),
+        "East wind thaws the ice"
+    );
+    assert_eq!(
+        lichun.short().translate_to_string(ChineseSimplified),
+        "立春初候"
+    );
+
+    // Lichun of 2024 is on 02-04, and the sun reaches 320° on 02-09
+    let date = |month, day| NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+    assert_eq!(Pentad::from_date(&date(2, 4)), Some(lichun));
+    let second = Pentad::new(SolarTerm::Lichun, 1).unwrap();
+    assert_eq!(second.date(2024), Some(date(2, 9)));
+    assert_eq!(Pentad::from_date(&date(2, 9)), Some(second));
+    assert_eq!(Pentad::from_date(&date(2, 8)), None);
+    assert_eq!(Pentad::containing(&date(2, 8)), Some(lichun));
+    // The last pentad of Dongzhi of 2023 reaches into 2024
+    let shuiquandong = Pentad::new(SolarTerm::Dongzhi, 2).unwrap();
+    assert_eq!(Pentad::containing(&date(1, 1)), Some(shuiquandong));
+    assert_eq!(
+        Pentad::containing(&date(12, 31)).map(|pentad| pentad.solar_term()),
+        Some(SolarTerm::Dongzhi)
+    );
+    // The pentads in effect at the beginning of months began in the month before
+    for (month, day) in [(8, 1), (9, 1), (10, 1), (10, 2), (11, 1), (12, 1)] {
+        let pentad = Pentad::containing(&date(month, day)).unwrap();
+        assert!(pentad.date(2024).unwrap() < date(month, 1), "{month}-{day}");
+    }
+    for month in 1..=12 {
+        for day in 1..=28 {
+            assert!(
+                Pentad::containing(&date(month, day)).is_some(),
+                "{month}-{day}"
+            );
+        }
+    }
+}
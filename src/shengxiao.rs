@@ -0,0 +1,169 @@
+//! The Chinese zodiac (生肖), the animals of the earthly branches of years.
+
+use chrono::{Datelike, NaiveDate};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{
+    chinese_date::ChineseYear,
+    ganzhi::Dizhi,
+    language::{
+        get_char_as_str, Language, ShortTranslate, StaticTranslate, Translate, SHENGXIAO_EN,
+        SHENGXIAO_S, SHENGXIAO_T,
+    },
+    ChineseDate, SolarTerm,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Shengxiao {
+    Shu,
+    Niu,
+    Hu,
+    Tu,
+    Long,
+    She,
+    Ma,
+    Yang,
+    Hou,
+    Ji,
+    Gou,
+    Zhu,
+}
+
+/// Where the animal of the year changes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum YearBoundary {
+    /// At Chunjie, as in folk custom.
+    #[default]
+    Chunjie,
+    /// At Lichun, as in fortune telling.
+    Lichun,
+}
+
+impl Shengxiao {
+    pub fn as_ordinal(self) -> u8 {
+        self as u8
+    }
+    pub fn from_ordinal(ordinal: u8) -> Option<Self> {
+        use Shengxiao::*;
+        match ordinal {
+            0 => Some(Shu),
+            1 => Some(Niu),
+            2 => Some(Hu),
+            3 => Some(Tu),
+            4 => Some(Long),
+            5 => Some(She),
+            6 => Some(Ma),
+            7 => Some(Yang),
+            8 => Some(Hou),
+            9 => Some(Ji),
+            10 => Some(Gou),
+            11 => Some(Zhu),
+            _ => None,
+        }
+    }
+    pub fn from_dizhi(dizhi: Dizhi) -> Self {
+        Self::from_ordinal(dizhi.as_ordinal()).unwrap()
+    }
+    pub fn dizhi(self) -> Dizhi {
+        Dizhi::from_ordinal(self.as_ordinal()).unwrap()
+    }
+    /// The animal of the year a date falls in, the year beginning at the boundary.
+    pub fn from_date(date: &impl Datelike, boundary: YearBoundary) -> Option<Self> {
+        Some(boundary.year_of(date)?.shengxiao())
+    }
+}
+
+impl YearBoundary {
+    /// The Chinese year a date falls in, the year beginning at the boundary.
+    pub fn year_of(self, date: &impl Datelike) -> Option<ChineseYear> {
+        match self {
+            YearBoundary::Chunjie => Some(ChineseDate::from_gregorian(date)?.chinese_year()),
+            YearBoundary::Lichun => {
+                let date = NaiveDate::from_ymd_opt(date.year(), date.month(), date.day())?;
+                Some(if date < SolarTerm::Lichun.date(date.year())? {
+                    ChineseYear(date.year() - 1)
+                } else {
+                    ChineseYear(date.year())
+                })
+            }
+        }
+    }
+}
+
+impl ChineseYear {
+    pub fn shengxiao(self) -> Shengxiao {
+        Shengxiao::from_dizhi(self.ganzhi().dizhi())
+    }
+}
+
+impl StaticTranslate for Shengxiao {
+    fn static_translate(&self, language: Language) -> &'static str {
+        let ordinal = self.as_ordinal() as usize;
+        match language {
+            Language::English => SHENGXIAO_EN[ordinal],
+            Language::ChineseSimplified => get_char_as_str(SHENGXIAO_S, ordinal).unwrap(),
+            Language::ChineseTraditional => get_char_as_str(SHENGXIAO_T, ordinal).unwrap(),
+            Language::Vietnamese => [
+                "Chuột", "Trâu", "Hổ", "Mèo", "Rồng", "Rắn", "Ngựa", "Dê", "Khỉ", "Gà", "Chó",
+                "Lợn",
+            ][ordinal],
+            Language::Korean => {
+                ["쥐", "소", "호랑이", "토끼", "용", "뱀", "말", "양", "원숭이", "닭", "개", "돼지"]
+                    [ordinal]
+            }
+            Language::Japanese => get_char_as_str("鼠牛虎兎竜蛇馬羊猿鶏犬猪", ordinal).unwrap(),
+        }
+    }
+}
+
+impl Translate for Shengxiao {
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        self.static_translate(language).fmt(f)
+    }
+}
+
+impl ShortTranslate for Shengxiao {
+    fn short_translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        self.translate(language, f)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    use Language::*;
+
+    let date = NaiveDate::from_ymd_opt(2025, 1, 20).unwrap();
+    assert_eq!(
+        Shengxiao::from_date(&date, YearBoundary::Chunjie),
+        Some(Shengxiao::Long)
+    );
+    assert_eq!(
+        Shengxiao::from_date(&date, YearBoundary::Lichun),
+        Some(Shengxiao::Long)
+    );
+    // Chunjie of 2025 is on 01-29, and Lichun on 02-03
+    let date = NaiveDate::from_ymd_opt(2025, 2, 1).unwrap();
+    assert_eq!(
+        Shengxiao::from_date(&date, YearBoundary::Chunjie),
+        Some(Shengxiao::She)
+    );
+    assert_eq!(
+        Shengxiao::from_date(&date, YearBoundary::Lichun),
+        Some(Shengxiao::Long)
+    );
+    let date = NaiveDate::from_ymd_opt(2025, 2, 3).unwrap();
+    assert_eq!(
+        Shengxiao::from_date(&date, YearBoundary::Lichun),
+        Some(Shengxiao::She)
+    );
+
+    assert_eq!(ChineseYear(1984).shengxiao(), Shengxiao::Shu);
+    assert_eq!(Shengxiao::Hu.dizhi(), Dizhi::Yin);
+    assert_eq!(
+        Shengxiao::Long.translate_to_string(ChineseTraditional),
+        "龍"
+    );
+    assert_eq!(Shengxiao::Tu.translate_to_string(Japanese), "兎");
+    assert_eq!(Shengxiao::Zhu.translate_to_string(English), "Pig");
+}
@@ -0,0 +1,272 @@
+//! Traditional time of day: the 12 double hours (时辰) named by the earthly branches, each split
+//! into an initial (初) and a proper (正) hour of 4 quarters (刻), and the 5 night watches (更) of
+//! 5 points (点) each.
+
+use chrono::{NaiveTime, TimeDelta, Timelike};
+use std::fmt::{Formatter, Result as FmtResult};
+
+use crate::{
+    ganzhi::Dizhi,
+    language::{get_char_as_str, Language, ShortTranslate, StaticTranslate, Translate, NUMBER},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Half {
+    /// The first hour, as 23:00 to 24:00 for Zi.
+    Chu,
+    /// The second hour, as 00:00 to 01:00 for Zi.
+    Zheng,
+}
+
+/// A half of a double hour.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Shichen {
+    pub dizhi: Dizhi,
+    pub half: Half,
+}
+
+/// A quarter of 15 minutes within a half of a double hour, numbered from 0 (初刻) to 3.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ke {
+    pub shichen: Shichen,
+    pub ke: u8,
+}
+
+/// A point of 24 minutes in the night watches from 19:00 to 05:00, both numbered from 1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Geng {
+    pub geng: u8,
+    pub dian: u8,
+}
+
+/// Traditional time of day, told by the night watches at night if chosen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TraditionalTime {
+    Ke(Ke),
+    Geng(Geng),
+}
+
+/// Start and end of a span of the day, which ends past midnight if the end is earlier.
+pub type TimeRange = (NaiveTime, NaiveTime);
+
+fn hour_range(hour: u32, minutes: i64, length: i64) -> TimeRange {
+    let start = NaiveTime::from_hms_opt(hour % 24, 0, 0).unwrap() + TimeDelta::minutes(minutes);
+    (start, start + TimeDelta::minutes(length))
+}
+
+impl Dizhi {
+    /// The double hour of the branch, as 23:00 to 01:00 for Zi.
+    pub fn hours(self) -> TimeRange {
+        hour_range(self.as_ordinal() as u32 * 2 + 23, 0, 120)
+    }
+}
+
+impl Shichen {
+    pub fn from_time(time: &impl Timelike) -> Self {
+        let hour = time.hour();
+        Self {
+            dizhi: Dizhi::from_ordinal((hour as u8).div_ceil(2) % 12).unwrap(),
+            half: if hour % 2 == 1 {
+                Half::Chu
+            } else {
+                Half::Zheng
+            },
+        }
+    }
+    pub fn range(self) -> TimeRange {
+        let hour = self.dizhi.as_ordinal() as u32 * 2 + 23 + self.half as u32;
+        hour_range(hour, 0, 60)
+    }
+}
+
+impl Ke {
+    pub fn from_time(time: &impl Timelike) -> Self {
+        Self {
+            shichen: Shichen::from_time(time),
+            ke: (time.minute() / 15) as u8,
+        }
+    }
+    pub fn range(self) -> TimeRange {
+        let (start, _) = self.shichen.range();
+        hour_range(start.hour(), self.ke as i64 * 15, 15)
+    }
+}
+
+impl Geng {
+    /// The night watch of a time, if it is between 19:00 and 05:00.
+    pub fn from_time(time: &impl Timelike) -> Option<Self> {
+        let minutes = (time.hour() * 60 + time.minute() + 5 * 60) % (24 * 60);
+        (minutes < 10 * 60).then(|| Self {
+            geng: (minutes / 120) as u8 + 1,
+            dian: (minutes % 120 / 24) as u8 + 1,
+        })
+    }
+    pub fn range(self) -> TimeRange {
+        let minutes = (self.geng as i64 - 1) * 120 + (self.dian as i64 - 1) * 24;
+        hour_range(19, minutes, 24)
+    }
+}
+
+impl TraditionalTime {
+    pub fn from_time(time: &impl Timelike, night_watches: bool) -> Self {
+        match Geng::from_time(time) {
+            Some(geng) if night_watches => Self::Geng(geng),
+            _ => Self::Ke(Ke::from_time(time)),
+        }
+    }
+    pub fn range(self) -> TimeRange {
+        match self {
+            Self::Ke(ke) => ke.range(),
+            Self::Geng(geng) => geng.range(),
+        }
+    }
+}
+
+impl Translate for Shichen {
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        let dizhi = self.dizhi.static_translate(language);
+        match (language, self.half) {
+            (Language::English, Half::Chu) => write!(f, "Early {dizhi} hour"),
+            (Language::English, Half::Zheng) => write!(f, "Late {dizhi} hour"),
+            (Language::Vietnamese, Half::Chu) => write!(f, "{dizhi} sơ"),
+            (Language::Vietnamese, Half::Zheng) => write!(f, "{dizhi} chính"),
+            (Language::Korean, Half::Chu) => write!(f, "{dizhi}초"),
+            (Language::Korean, Half::Zheng) => write!(f, "{dizhi}정"),
+            (_, Half::Chu) => write!(f, "{dizhi}初"),
+            (_, Half::Zheng) => write!(f, "{dizhi}正"),
+        }
+    }
+}
+
+impl ShortTranslate for Shichen {
+    /// The double hour only, as "午时".
+    fn short_translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        let dizhi = self.dizhi.static_translate(language);
+        match language {
+            Language::English => write!(f, "{dizhi} hour"),
+            Language::ChineseSimplified => write!(f, "{dizhi}时"),
+            Language::Vietnamese => write!(f, "Giờ {dizhi}"),
+            Language::Korean => write!(f, "{dizhi}시"),
+            Language::ChineseTraditional | Language::Japanese => write!(f, "{dizhi}時"),
+        }
+    }
+}
+
+impl Translate for Ke {
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        let shichen = self.shichen.translate_adapter(language);
+        let ke = self.ke as usize;
+        match language {
+            Language::English => write!(f, "{shichen}, quarter {ke}"),
+            Language::Vietnamese => {
+                let ke = ["sơ", "nhất", "nhị", "tam"][ke];
+                write!(f, "{shichen} {ke} khắc")
+            }
+            Language::Korean => {
+                write!(
+                    f,
+                    "{shichen} {}각",
+                    get_char_as_str("초일이삼", ke).unwrap()
+                )
+            }
+            _ => write!(f, "{shichen}{}刻", get_char_as_str("初一二三", ke).unwrap()),
+        }
+    }
+}
+
+impl Translate for Geng {
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        let (geng, dian) = (self.geng as usize - 1, self.dian as usize - 1);
+        match language {
+            Language::English => {
+                let ordinals = ["1st", "2nd", "3rd", "4th", "5th"];
+                write!(f, "{} watch, {} point", ordinals[geng], ordinals[dian])
+            }
+            Language::Vietnamese => write!(
+                f,
+                "Canh {}, điểm {}",
+                ["một", "hai", "ba", "tư", "năm"][geng],
+                ["một", "hai", "ba", "bốn", "năm"][dian]
+            ),
+            Language::Korean => write!(
+                f,
+                "{}경 {}점",
+                get_char_as_str("일이삼사오", geng).unwrap(),
+                get_char_as_str("일이삼사오", dian).unwrap()
+            ),
+            _ => write!(
+                f,
+                "{}更{}{}",
+                get_char_as_str(NUMBER, geng).unwrap(),
+                get_char_as_str(NUMBER, dian).unwrap(),
+                if language == Language::ChineseTraditional {
+                    "點"
+                } else {
+                    "点"
+                }
+            ),
+        }
+    }
+}
+
+impl Translate for TraditionalTime {
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        match self {
+            Self::Ke(ke) => ke.translate(language, f),
+            Self::Geng(geng) => geng.translate(language, f),
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    use Language::*;
+
+    let time = |hour, minute| NaiveTime::from_hms_opt(hour, minute, 0).unwrap();
+
+    let ke = Ke::from_time(&time(11, 45));
+    assert_eq!(ke.shichen.dizhi, Dizhi::Wu);
+    assert_eq!(ke.shichen.half, Half::Chu);
+    assert_eq!(ke.ke, 3);
+    assert_eq!(ke.translate_to_string(ChineseSimplified), "午初三刻");
+    assert_eq!(ke.translate_to_string(Korean), "오초 삼각");
+    assert_eq!(ke.translate_to_string(English), "Early Wu hour, quarter 3");
+    assert_eq!(ke.range(), (time(11, 45), time(12, 0)));
+    assert_eq!(
+        ke.shichen.short().translate_to_string(ChineseTraditional),
+        "午時"
+    );
+
+    let zi = Shichen::from_time(&time(23, 10));
+    assert_eq!(zi.dizhi, Dizhi::Zi);
+    assert_eq!(zi.range(), (time(23, 0), time(0, 0)));
+    assert_eq!(Shichen::from_time(&time(0, 10)).half, Half::Zheng);
+    assert_eq!(Dizhi::Zi.hours(), (time(23, 0), time(1, 0)));
+    assert_eq!(Dizhi::Hai.hours(), (time(21, 0), time(23, 0)));
+    for minutes in (0..24 * 60).step_by(7) {
+        let now = time(minutes / 60, minutes % 60);
+        let ke = Ke::from_time(&now);
+        let (start, end) = ke.range();
+        assert!(start <= now && (now < end || end == time(0, 0)));
+    }
+
+    assert_eq!(Geng::from_time(&time(18, 59)), None);
+    assert_eq!(Geng::from_time(&time(5, 0)), None);
+    let geng = Geng::from_time(&time(0, 30)).unwrap();
+    assert_eq!(geng, Geng { geng: 3, dian: 4 });
+    assert_eq!(geng.range(), (time(0, 12), time(0, 36)));
+    assert_eq!(geng.translate_to_string(ChineseTraditional), "三更四點");
+    assert_eq!(
+        TraditionalTime::from_time(&time(0, 30), true),
+        TraditionalTime::Geng(geng)
+    );
+    assert_eq!(
+        TraditionalTime::from_time(&time(0, 30), false).translate_to_string(ChineseSimplified),
+        "子正二刻"
+    );
+    assert_eq!(
+        TraditionalTime::from_time(&time(4, 59), true).translate_to_string(Vietnamese),
+        "Canh năm, điểm năm"
+    );
+}
@@ -1,4 +1,10 @@
-use crate::data::SOLAR_TERMS;
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, TimeZone};
+
+use crate::{
+    astronomy::{datetime, julian_day, sun_longitude_instant},
+    chinese_date::{BEIJING_OFFSET, YEARS},
+    data::SOLAR_TERMS,
+};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum SolarTerm {
@@ -62,25 +68,61 @@ impl SolarTerm {
             _ => None,
         }
     }
-    pub fn from_date(date: &impl chrono::Datelike) -> Option<SolarTerm> {
-        let year = date.year();
-        if year < 1900 {
-            return None;
-        }
-        let solar_terms = SOLAR_TERMS.get(year as usize - 1900)?;
+    pub fn from_date(date: &impl Datelike) -> Option<SolarTerm> {
+        let date = NaiveDate::from_ymd_opt(date.year(), date.month(), date.day())?;
+        Self::from_date_at(date, BEIJING_OFFSET)
+    }
+    /// The term beginning on a date, reckoned at a UTC offset in seconds.
+    pub(crate) fn from_date_at(date: NaiveDate, offset: i32) -> Option<SolarTerm> {
         let ordinal0 = date.month0() as u8 * 2;
-        let ordinal1 = ordinal0 + 1;
-        let day = date.day() as u8;
-        if day == solar_terms[ordinal0 as usize] {
-            SolarTerm::from_ordinal(ordinal0)
-        } else if day == solar_terms[ordinal1 as usize] {
-            SolarTerm::from_ordinal(ordinal1)
+        [ordinal0, ordinal0 + 1]
+            .into_iter()
+            .filter_map(SolarTerm::from_ordinal)
+            .find(|solar_term| solar_term.date_at(date.year(), offset) == Some(date))
+    }
+    /// Apparent ecliptic longitude of the sun at the beginning of the term, in degrees.
+    pub fn longitude(self) -> f64 {
+        ((self.as_ordinal() as u16 * 15 + 285) % 360) as f64
+    }
+    /// Date of the term in a Gregorian year, in Beijing time.
+    ///
+    /// This is the date of [`Self::instant`], read from a table of them for years in `1900..=2039`.
+    pub fn date(self, year: i32) -> Option<NaiveDate> {
+        self.date_at(year, BEIJING_OFFSET)
+    }
+    pub(crate) fn date_at(self, year: i32, offset: i32) -> Option<NaiveDate> {
+        if let Some(solar_terms) = usize::try_from(year - 1900)
+            .ok()
+            .and_then(|index| SOLAR_TERMS.get(index))
+            .filter(|_| offset == BEIJING_OFFSET)
+        {
+            let ordinal = self.as_ordinal();
+            NaiveDate::from_ymd_opt(
+                year,
+                ordinal as u32 / 2 + 1,
+                solar_terms[ordinal as usize] as u32,
+            )
         } else {
-            None
+            self.instant_in(year, &FixedOffset::east_opt(offset)?)
+                .map(|instant| instant.date_naive())
         }
     }
+    /// Instant when the term begins in a Gregorian year, in Beijing time (UTC+8).
+    pub fn instant(self, year: i32) -> Option<DateTime<FixedOffset>> {
+        self.instant_in(year, &FixedOffset::east_opt(BEIJING_OFFSET)?)
+    }
+    /// Instant when the term begins in a Gregorian year, in a time zone.
+    pub fn instant_in<Tz: TimeZone>(self, year: i32, tz: &Tz) -> Option<DateTime<Tz>> {
+        if !YEARS.contains(&year) {
+            return None;
+        }
+        let guess =
+            julian_day(NaiveDate::from_ymd_opt(year, 1, 6)?) + self.as_ordinal() as f64 * 15.2;
+        datetime(sun_longitude_instant(self.longitude(), guess))
+            .map(|instant| instant.with_timezone(tz))
+    }
     pub fn is_midterm(self) -> bool {
-        self.as_ordinal() % 2 > 0
+        self.as_ordinal() % 2 == 1
     }
 }
 
@@ -92,11 +134,52 @@ fn test() {
         for (j, day) in solar_terms.iter().enumerate() {
             let month = j as u32 / 2 + 1;
             let date = chrono::NaiveDate::from_ymd_opt(year, month, *day as u32).unwrap();
-            dbg!(date);
             assert_eq!(
                 SolarTerm::from_date(&date),
                 SolarTerm::from_ordinal(j as u8)
             );
+            // The table holds the dates of the instants in Beijing time
+            let solar_term = SolarTerm::from_ordinal(j as u8).unwrap();
+            assert_eq!(
+                solar_term.instant(year).map(|instant| instant.date_naive()),
+                Some(date)
+            );
+        }
+    }
+    for (solar_term, year, month, day) in [
+        (SolarTerm::Qingming, 2007, 4, 5),
+        (SolarTerm::Guyu, 2007, 4, 20),
+        (SolarTerm::Liqiu, 2020, 8, 7),
+        (SolarTerm::Dongzhi, 2006, 12, 22),
+        (SolarTerm::Xiaoman, 2039, 5, 21),
+    ] {
+        let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        assert_eq!(solar_term.date(year), Some(date));
+        assert_eq!(SolarTerm::from_date(&date), Some(solar_term));
+    }
+    // At other offsets, the dates are those of the instants there
+    for offset in [-5 * 3600, 0, 9 * 3600] {
+        let tz = FixedOffset::east_opt(offset).unwrap();
+        for solar_term in (0..24).map(|i| SolarTerm::from_ordinal(i).unwrap()) {
+            let date = solar_term.instant_in(2007, &tz).unwrap().date_naive();
+            assert_eq!(solar_term.date_at(2007, offset), Some(date));
+            assert_eq!(SolarTerm::from_date_at(date, offset), Some(solar_term));
+        }
+    }
+
+    let dongzhi = SolarTerm::Dongzhi.instant(2025).unwrap();
+    assert_eq!(dongzhi.format("%F %R").to_string(), "2025-12-21 23:03");
+    assert_eq!(
+        SolarTerm::Dongzhi.instant_in(2025, &chrono::Utc).unwrap(),
+        dongzhi
+    );
+    for year in [-1000, 1899, 2040, 3000] {
+        let mut dates = (0..24).map(|i| SolarTerm::from_ordinal(i).unwrap().date(year).unwrap());
+        let mut last = dates.next().unwrap();
+        assert_eq!(last.month(), 1);
+        for date in dates {
+            assert!(last < date && date.year() == year);
+            last = date;
         }
     }
 }
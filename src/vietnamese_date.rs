@@ -0,0 +1,79 @@
+//! The Vietnamese lunar calendar (Âm lịch), which follows the rules of the Chinese calendar
+//! reckoned at UTC+7.
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::{
+    chinese_date::{chunjie_at, days_of_chinese_month_at, ChineseYear},
+    ChineseDate,
+};
+
+/// UTC offset of Vietnam, in seconds.
+pub const VIETNAM_OFFSET: i32 = 7 * 3600;
+
+/// A date in the Vietnamese calendar, whose year, month and day are labelled as in
+/// [`ChineseDate`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct VietnameseDate(pub ChineseDate);
+
+/// Gregorian date of Tết Nguyên Đán in a Vietnamese year.
+pub fn tet(year: ChineseYear) -> Option<NaiveDate> {
+    chunjie_at(year, Some(VIETNAM_OFFSET))
+}
+
+impl VietnameseDate {
+    pub fn new(year: i32, month: u8, leap: bool, day: u8) -> Option<Self> {
+        ChineseDate::new(year, month, leap, day).map(Self)
+    }
+    pub fn from_gregorian(date: &impl Datelike) -> Option<Self> {
+        let date = NaiveDate::from_ymd_opt(date.year(), date.month(), date.day())?;
+        ChineseDate::from_gregorian_at(date, Some(VIETNAM_OFFSET)).map(Self)
+    }
+    pub fn to_gregorian(&self) -> NaiveDate {
+        self.0.to_gregorian_at(Some(VIETNAM_OFFSET)).unwrap()
+    }
+    pub fn days_of_month(&self) -> u8 {
+        days_of_chinese_month_at(
+            self.0.chinese_year(),
+            self.0.chinese_month(),
+            Some(VIETNAM_OFFSET),
+        )
+        .unwrap()
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    use crate::chinese_date::chunjie;
+
+    // Years whose Tết differs from Chunjie
+    for (year, tet_date, chunjie_date) in
+        [(1985, (1985, 1, 21), (1985, 2, 20)), (2007, (2007, 2, 17), (2007, 2, 18))]
+    {
+        let year = ChineseYear::new(year).unwrap();
+        let (y, m, d) = tet_date;
+        assert_eq!(tet(year), NaiveDate::from_ymd_opt(y, m, d));
+        let (y, m, d) = chunjie_date;
+        assert_eq!(chunjie(year), NaiveDate::from_ymd_opt(y, m, d));
+    }
+
+    // The tenth month of 1984 has a leap month in China but not in Vietnam
+    let date = NaiveDate::from_ymd_opt(1984, 11, 23).unwrap();
+    assert_eq!(
+        VietnameseDate::from_gregorian(&date),
+        VietnameseDate::new(1984, 11, false, 1)
+    );
+    assert_eq!(
+        ChineseDate::from_gregorian(&date),
+        ChineseDate::new(1984, 10, true, 1)
+    );
+
+    for year in [1968, 1985, 2007, 2025] {
+        let tet = tet(ChineseYear::new(year).unwrap()).unwrap();
+        for date in tet.iter_days().step_by(11).take(40) {
+            let vietnamese_date = VietnameseDate::from_gregorian(&date).unwrap();
+            assert_eq!(vietnamese_date.to_gregorian(), date);
+        }
+    }
+}
@@ -0,0 +1,331 @@
+//! The five phases (五行), the polarity of yin and yang (阴阳) of the stems and branches, and the
+//! sound of the sexagenary cycle (纳音).
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{
+    ganzhi::{Dizhi, Ganzhi, Tiangan},
+    language::{
+        get_char_as_str, Language, ShortTranslate, StaticTranslate, Translate, WUXING, WUXING_EN,
+        WUXING_KO, WUXING_VI,
+    },
+};
+
+/// The five phases, in the order each generates the next.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Wuxing {
+    Mu,
+    Huo,
+    Tu,
+    Jin,
+    Shui,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum YinYang {
+    Yang,
+    Yin,
+}
+
+/// One of the 30 sounds, each shared by two consecutive ganzhi, as Jiazi and Yichou are both
+/// "gold in the sea" (海中金).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Nayin(u8);
+
+const NAYIN_S: &str = "海中金炉中火大林木路旁土剑锋金山头火涧下水城头土白蜡金杨柳木\
+                       泉中水屋上土霹雳火松柏木长流水沙中金山下火平地木壁上土金箔金\
+                       覆灯火天河水大驿土钗钏金桑柘木大溪水沙中土天上火石榴木大海水";
+const NAYIN_T: &str = "海中金爐中火大林木路旁土劍鋒金山頭火澗下水城頭土白蠟金楊柳木\
+                       泉中水屋上土霹靂火松柏木長流水沙中金山下火平地木壁上土金箔金\
+                       覆燈火天河水大驛土釵釧金桑柘木大溪水沙中土天上火石榴木大海水";
+const NAYIN_JA: &str = "海中金炉中火大林木路傍土剣鋒金山頭火澗下水城頭土白鑞金楊柳木\
+                        井泉水屋上土霹靂火松柏木長流水砂中金山下火平地木壁上土金箔金\
+                        覆灯火天河水大駅土釵釧金桑柘木大渓水砂中土天上火柘榴木大海水";
+const NAYIN_KO: &str = "해중금노중화대림목노방토검봉금산두화간하수성두토백랍금양류목\
+                        천중수옥상토벽력화송백목장류수사중금산하화평지목벽상토금박금\
+                        복등화천하수대역토차천금상자목대계수사중토천상화석류목대해수";
+const NAYIN_EN: &[&str] = &[
+    "Metal in the Sea",
+    "Fire in the Furnace",
+    "Wood of the Great Forest",
+    "Earth by the Roadside",
+    "Metal of the Sword Blade",
+    "Fire on the Mountain Top",
+    "Water in the Ravine",
+    "Earth on the City Wall",
+    "Metal of White Wax",
+    "Wood of the Willow",
+    "Water in the Spring",
+    "Earth on the Roof",
+    "Fire of the Thunderbolt",
+    "Wood of Pine and Cypress",
+    "Water of the Long River",
+    "Metal in the Sand",
+    "Fire at the Mountain Foot",
+    "Wood of the Plain",
+    "Earth on the Wall",
+    "Metal of Gold Foil",
+    "Fire of the Lamp",
+    "Water of the Milky Way",
+    "Earth of the Great Post Road",
+    "Metal of Hairpins and Bracelets",
+    "Wood of the Mulberry",
+    "Water of the Great Stream",
+    "Earth in the Sand",
+    "Fire in the Sky",
+    "Wood of the Pomegranate",
+    "Water of the Great Sea",
+];
+const NAYIN_VI: &[&str] = &[
+    "Hải trung kim",
+    "Lư trung hỏa",
+    "Đại lâm mộc",
+    "Lộ bàng thổ",
+    "Kiếm phong kim",
+    "Sơn đầu hỏa",
+    "Giản hạ thủy",
+    "Thành đầu thổ",
+    "Bạch lạp kim",
+    "Dương liễu mộc",
+    "Tuyền trung thủy",
+    "Ốc thượng thổ",
+    "Tích lịch hỏa",
+    "Tùng bách mộc",
+    "Trường lưu thủy",
+    "Sa trung kim",
+    "Sơn hạ hỏa",
+    "Bình địa mộc",
+    "Bích thượng thổ",
+    "Kim bạch kim",
+    "Phú đăng hỏa",
+    "Thiên hà thủy",
+    "Đại trạch thổ",
+    "Thoa xuyến kim",
+    "Tang đố mộc",
+    "Đại khê thủy",
+    "Sa trung thổ",
+    "Thiên thượng hỏa",
+    "Thạch lựu mộc",
+    "Đại hải thủy",
+];
+
+impl Wuxing {
+    pub fn as_ordinal(self) -> u8 {
+        self as u8
+    }
+    pub fn from_ordinal(ordinal: u8) -> Option<Self> {
+        use Wuxing::*;
+        match ordinal {
+            0 => Some(Mu),
+            1 => Some(Huo),
+            2 => Some(Tu),
+            3 => Some(Jin),
+            4 => Some(Shui),
+            _ => None,
+        }
+    }
+    /// The phase this one generates (相生), as wood feeds fire.
+    pub fn generates(self) -> Self {
+        Self::from_ordinal((self.as_ordinal() + 1) % 5).unwrap()
+    }
+    /// The phase that generates this one.
+    pub fn generated_by(self) -> Self {
+        Self::from_ordinal((self.as_ordinal() + 4) % 5).unwrap()
+    }
+    /// The phase this one overcomes (相克), as wood parts earth.
+    pub fn overcomes(self) -> Self {
+        Self::from_ordinal((self.as_ordinal() + 2) % 5).unwrap()
+    }
+    /// The phase that overcomes this one.
+    pub fn overcome_by(self) -> Self {
+        Self::from_ordinal((self.as_ordinal() + 3) % 5).unwrap()
+    }
+}
+
+impl YinYang {
+    fn from_parity(ordinal: u8) -> Self {
+        match ordinal % 2 {
+            0 => YinYang::Yang,
+            _ => YinYang::Yin,
+        }
+    }
+}
+
+impl Nayin {
+    /// Ordinal among the 30 sounds, from 0 for "gold in the sea".
+    pub fn as_ordinal(self) -> u8 {
+        self.0
+    }
+    pub fn from_ordinal(ordinal: u8) -> Option<Self> {
+        (ordinal < 30).then_some(Self(ordinal))
+    }
+    /// The phase of the sound, the last character of its name.
+    pub fn wuxing(self) -> Wuxing {
+        let name = get_char_as_str(NAYIN_S, self.0 as usize * 3 + 2).unwrap();
+        let ordinal = WUXING.find(name).unwrap() / 3;
+        Wuxing::from_ordinal(ordinal as u8).unwrap()
+    }
+}
+
+impl Tiangan {
+    /// The phase of the stem, shared by each yang stem and the yin stem after it.
+    pub fn wuxing(self) -> Wuxing {
+        Wuxing::from_ordinal(self.as_ordinal() / 2).unwrap()
+    }
+    pub fn yin_yang(self) -> YinYang {
+        YinYang::from_parity(self.as_ordinal())
+    }
+}
+
+impl Dizhi {
+    /// The phase of the branch. The branches ending each season, Chen, Wei, Xu and Chou, are
+    /// of earth.
+    pub fn wuxing(self) -> Wuxing {
+        use Dizhi::*;
+        match self {
+            Yin | Mao => Wuxing::Mu,
+            Si | Wu => Wuxing::Huo,
+            Shen | You => Wuxing::Jin,
+            Hai | Zi => Wuxing::Shui,
+            Chen | Wei | Xu | Chou => Wuxing::Tu,
+        }
+    }
+    pub fn yin_yang(self) -> YinYang {
+        YinYang::from_parity(self.as_ordinal())
+    }
+}
+
+impl Ganzhi {
+    pub fn nayin(self) -> Nayin {
+        Nayin(self.as_ordinal() / 2)
+    }
+    /// Polarity of the ganzhi, which its stem and branch share.
+    pub fn yin_yang(self) -> YinYang {
+        self.tiangan().yin_yang()
+    }
+}
+
+impl StaticTranslate for Wuxing {
+    fn static_translate(&self, language: Language) -> &'static str {
+        let ordinal = self.as_ordinal() as usize;
+        match language {
+            Language::English => WUXING_EN[ordinal],
+            Language::Vietnamese => WUXING_VI[ordinal],
+            Language::Korean => get_char_as_str(WUXING_KO, ordinal).unwrap(),
+            _ => get_char_as_str(WUXING, ordinal).unwrap(),
+        }
+    }
+}
+
+impl StaticTranslate for YinYang {
+    fn static_translate(&self, language: Language) -> &'static str {
+        use Language::*;
+        match (self, language) {
+            (YinYang::Yang, English) => "Yang",
+            (YinYang::Yin, English) => "Yin",
+            (YinYang::Yang, ChineseSimplified) => "阳",
+            (YinYang::Yin, ChineseSimplified) => "阴",
+            (YinYang::Yang, ChineseTraditional | Japanese) => "陽",
+            (YinYang::Yin, ChineseTraditional | Japanese) => "陰",
+            (YinYang::Yang, Vietnamese) => "Dương",
+            (YinYang::Yin, Vietnamese) => "Âm",
+            (YinYang::Yang, Korean) => "양",
+            (YinYang::Yin, Korean) => "음",
+        }
+    }
+}
+
+impl StaticTranslate for Nayin {
+    fn static_translate(&self, language: Language) -> &'static str {
+        let ordinal = self.0 as usize;
+        let names = match language {
+            Language::English => return NAYIN_EN[ordinal],
+            Language::Vietnamese => return NAYIN_VI[ordinal],
+            Language::ChineseSimplified => NAYIN_S,
+            Language::ChineseTraditional => NAYIN_T,
+            Language::Korean => NAYIN_KO,
+            Language::Japanese => NAYIN_JA,
+        };
+        names.get(ordinal * 9..ordinal * 9 + 9).unwrap()
+    }
+}
+
+impl Translate for Wuxing {
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        self.static_translate(language).fmt(f)
+    }
+}
+
+impl ShortTranslate for Wuxing {
+    fn short_translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        self.translate(language, f)
+    }
+}
+
+impl Translate for YinYang {
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        self.static_translate(language).fmt(f)
+    }
+}
+
+impl ShortTranslate for YinYang {
+    fn short_translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        self.translate(language, f)
+    }
+}
+
+impl Translate for Nayin {
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        self.static_translate(language).fmt(f)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    use Language::*;
+
+    assert_eq!(Wuxing::Mu.generates(), Wuxing::Huo);
+    assert_eq!(Wuxing::Shui.generates(), Wuxing::Mu);
+    assert_eq!(Wuxing::Mu.overcomes(), Wuxing::Tu);
+    assert_eq!(Wuxing::Shui.overcomes(), Wuxing::Huo);
+    for ordinal in 0..5 {
+        let wuxing = Wuxing::from_ordinal(ordinal).unwrap();
+        assert_eq!(wuxing.generates().generated_by(), wuxing);
+        assert_eq!(wuxing.overcomes().overcome_by(), wuxing);
+    }
+
+    assert_eq!(Tiangan::Geng.wuxing(), Wuxing::Jin);
+    assert_eq!(Tiangan::Gui.yin_yang(), YinYang::Yin);
+    assert_eq!(Dizhi::Chou.wuxing(), Wuxing::Tu);
+    assert_eq!(Dizhi::Hai.wuxing(), Wuxing::Shui);
+    assert_eq!(Dizhi::Wu.yin_yang(), YinYang::Yang);
+
+    let jiazi = Ganzhi::from_ordinal(0).unwrap();
+    assert_eq!(jiazi.nayin(), (jiazi + 1).nayin());
+    assert_eq!(jiazi.nayin().wuxing(), Wuxing::Jin);
+    assert_eq!(
+        jiazi.nayin().translate_to_string(ChineseSimplified),
+        "海中金"
+    );
+    let guihai = Ganzhi::from_ordinal(59).unwrap();
+    assert_eq!(guihai.nayin().wuxing(), Wuxing::Shui);
+    assert_eq!(
+        guihai.nayin().translate_to_string(English),
+        "Water of the Great Sea"
+    );
+    for ordinal in 0..30 {
+        let nayin = Nayin::from_ordinal(ordinal).unwrap();
+        let wuxing = nayin.wuxing().static_translate(Vietnamese).to_lowercase();
+        assert!(nayin.static_translate(Vietnamese).ends_with(&wuxing));
+        for language in Language::ALL.iter().copied() {
+            let name = nayin.static_translate(language);
+            assert!(!name.is_empty());
+            if language.is_cjk() {
+                assert_eq!(name.chars().count(), 3);
+            }
+        }
+    }
+    assert_eq!(Wuxing::Tu.translate_to_string(Korean), "토");
+    assert_eq!(YinYang::Yin.translate_to_string(ChineseTraditional), "陰");
+}
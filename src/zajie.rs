@@ -0,0 +1,407 @@
+//! Seasonal days (杂节) reckoned from solar terms and day ganzhi: the dog days (三伏) after Xiazhi,
+//! the nine-nines (数九) after Dongzhi, the plum rains (梅雨) and the She days (社日).
+
+use chrono::{Datelike, Days, NaiveDate};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{
+    astronomy::{julian_day, local_date, sun_longitude_instant},
+    ganzhi::{Dizhi, Ganzhi, Tiangan},
+    japanese_date::JAPAN_OFFSET,
+    language::{
+        abbreviate_vietnamese, get_char_as_str, Language, ShortTranslate, StaticTranslate,
+        Translate, NUMBER,
+    },
+    SolarTerm,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fu {
+    Chufu,
+    Zhongfu,
+    Mofu,
+}
+
+/// The first days of the three fu of a year. Chufu and Mofu last 10 days, and Zhongfu lasts until
+/// Mofu, for 10 or 20 days.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sanfu {
+    pub chufu: NaiveDate,
+    pub zhongfu: NaiveDate,
+    pub mofu: NaiveDate,
+}
+
+/// The nine periods of 9 days each, counted from Dongzhi.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Shujiu {
+    pub dongzhi: NaiveDate,
+}
+
+/// Rule to find the plum rains.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MeiyuRule {
+    /// The first bing day after Mangzhong to the first wei day after Xiaoshu (芒种后逢丙入梅，小暑后逢未
+    /// 出梅).
+    #[default]
+    BingWei,
+    /// The first ren day after Mangzhong to the first geng day after Xiazhi (芒种后逢壬入梅，夏至后逢庚
+    /// 出梅).
+    RenGeng,
+    /// The day in Japan when the sun reaches 80° of longitude, with no end.
+    Japanese,
+}
+
+/// The start (入梅) and the end (出梅) of the plum rains of a year.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Meiyu {
+    pub rumei: NaiveDate,
+    pub chumei: Option<NaiveDate>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum She {
+    Chunshe,
+    Qiushe,
+}
+
+/// Rule to find the She days.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SheRule {
+    /// The fifth wu day after Lichun or Liqiu.
+    #[default]
+    FifthWu,
+    /// The wu day nearest to Chunfen or Qiufen, or the earlier one if both are 5 days away, as in
+    /// Japan.
+    NearestEquinox,
+}
+
+/// A day in a seasonal period, numbered from 1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Period {
+    Fu(Fu, u8),
+    /// A day in the nine-nines, where the first number counts the nine from 1 to 9.
+    Jiu(u8, u8),
+}
+
+/// The first day on or after a date with the stem.
+fn tiangan_day(date: NaiveDate, tiangan: Tiangan) -> Option<NaiveDate> {
+    let steps = tiangan - Ganzhi::of_day(&date).tiangan();
+    date.checked_add_days(Days::new(steps as u64))
+}
+
+/// The first day on or after a date with the branch.
+fn dizhi_day(date: NaiveDate, dizhi: Dizhi) -> Option<NaiveDate> {
+    let steps = dizhi - Ganzhi::of_day(&date).dizhi();
+    date.checked_add_days(Days::new(steps as u64))
+}
+
+/// The first day after a solar term with the stem.
+fn tiangan_day_after(term: SolarTerm, year: i32, tiangan: Tiangan) -> Option<NaiveDate> {
+    tiangan_day(term.date(year)?.succ_opt()?, tiangan)
+}
+
+fn day_in(date: NaiveDate, start: NaiveDate, length: i64) -> Option<u8> {
+    let days = (date - start).num_days();
+    (0..length).contains(&days).then(|| days as u8 + 1)
+}
+
+impl Sanfu {
+    /// Chufu begins on the third geng day from Xiazhi, Zhongfu on the fourth, and Mofu on the
+    /// first geng day from Liqiu, counting the days of the terms themselves.
+    pub fn new(year: i32) -> Option<Self> {
+        let chufu = tiangan_day(SolarTerm::Xiazhi.date(year)?, Tiangan::Geng)?
+            .checked_add_days(Days::new(20))?;
+        Some(Self {
+            chufu,
+            zhongfu: chufu.checked_add_days(Days::new(10))?,
+            mofu: tiangan_day(SolarTerm::Liqiu.date(year)?, Tiangan::Geng)?,
+        })
+    }
+    /// The day after Mofu, when the dog days end (出伏).
+    pub fn end(&self) -> NaiveDate {
+        self.mofu + Days::new(10)
+    }
+    pub fn period(&self, date: &impl Datelike) -> Option<Period> {
+        let date = NaiveDate::from_ymd_opt(date.year(), date.month(), date.day())?;
+        let zhongfu_length = (self.mofu - self.zhongfu).num_days();
+        day_in(date, self.chufu, 10)
+            .map(|day| Period::Fu(Fu::Chufu, day))
+            .or_else(|| {
+                day_in(date, self.zhongfu, zhongfu_length).map(|day| Period::Fu(Fu::Zhongfu, day))
+            })
+            .or_else(|| day_in(date, self.mofu, 10).map(|day| Period::Fu(Fu::Mofu, day)))
+    }
+}
+
+impl Shujiu {
+    /// The nine-nines starting at Dongzhi of a Gregorian year.
+    pub fn new(year: i32) -> Option<Self> {
+        SolarTerm::Dongzhi
+            .date(year)
+            .map(|dongzhi| Self { dongzhi })
+    }
+    /// The day after the ninth nine.
+    pub fn end(&self) -> NaiveDate {
+        self.dongzhi + Days::new(81)
+    }
+    pub fn period(&self, date: &impl Datelike) -> Option<Period> {
+        let date = NaiveDate::from_ymd_opt(date.year(), date.month(), date.day())?;
+        let day = day_in(date, self.dongzhi, 81)? - 1;
+        Some(Period::Jiu(day / 9 + 1, day % 9 + 1))
+    }
+}
+
+impl Meiyu {
+    pub fn new(year: i32, rule: MeiyuRule) -> Option<Self> {
+        match rule {
+            MeiyuRule::BingWei => Some(Self {
+                rumei: tiangan_day_after(SolarTerm::Mangzhong, year, Tiangan::Bing)?,
+                chumei: Some(dizhi_day(
+                    SolarTerm::Xiaoshu.date(year)?.succ_opt()?,
+                    Dizhi::Wei,
+                )?),
+            }),
+            MeiyuRule::RenGeng => Some(Self {
+                rumei: tiangan_day_after(SolarTerm::Mangzhong, year, Tiangan::Ren)?,
+                chumei: Some(tiangan_day_after(SolarTerm::Xiazhi, year, Tiangan::Geng)?),
+            }),
+            MeiyuRule::Japanese => {
+                let guess = julian_day(NaiveDate::from_ymd_opt(year, 6, 11)?);
+                Some(Self {
+                    rumei: local_date(sun_longitude_instant(80.0, guess), JAPAN_OFFSET)?,
+                    chumei: None,
+                })
+            }
+        }
+    }
+}
+
+impl She {
+    pub fn date(self, year: i32, rule: SheRule) -> Option<NaiveDate> {
+        match rule {
+            SheRule::FifthWu => {
+                let term = match self {
+                    She::Chunshe => SolarTerm::Lichun,
+                    She::Qiushe => SolarTerm::Liqiu,
+                };
+                tiangan_day_after(term, year, Tiangan::Wu)?.checked_add_days(Days::new(40))
+            }
+            SheRule::NearestEquinox => {
+                let equinox = match self {
+                    She::Chunshe => SolarTerm::Chunfen,
+                    She::Qiushe => SolarTerm::Qiufen,
+                }
+                .date(year)?;
+                tiangan_day(equinox.checked_sub_days(Days::new(5))?, Tiangan::Wu)
+            }
+        }
+    }
+}
+
+impl Period {
+    /// The dog day or the day of the nine-nines a date falls in.
+    pub fn from_date(date: &impl Datelike) -> Option<Self> {
+        let year = date.year();
+        Sanfu::new(year)
+            .and_then(|sanfu| sanfu.period(date))
+            .or_else(|| Shujiu::new(year - 1).and_then(|shujiu| shujiu.period(date)))
+            .or_else(|| Shujiu::new(year).and_then(|shujiu| shujiu.period(date)))
+    }
+    pub fn day(self) -> u8 {
+        match self {
+            Period::Fu(_, day) | Period::Jiu(_, day) => day,
+        }
+    }
+}
+
+impl StaticTranslate for Fu {
+    fn static_translate(&self, language: Language) -> &'static str {
+        use Fu::*;
+        use Language::*;
+        match language {
+            English => ["Chufu", "Zhongfu", "Mofu"][*self as usize],
+            Vietnamese => ["Sơ phục", "Trung phục", "Mạt phục"][*self as usize],
+            Korean => ["초복", "중복", "말복"][*self as usize],
+            _ => match self {
+                Chufu => "初伏",
+                Zhongfu => "中伏",
+                Mofu => "末伏",
+            },
+        }
+    }
+}
+
+impl Translate for Fu {
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        self.static_translate(language).fmt(f)
+    }
+}
+
+impl StaticTranslate for She {
+    fn static_translate(&self, language: Language) -> &'static str {
+        use Language::*;
+        use She::*;
+        match (self, language) {
+            (Chunshe, English) => "Spring She day",
+            (Qiushe, English) => "Autumn She day",
+            (Chunshe, Vietnamese) => "Xuân xã",
+            (Qiushe, Vietnamese) => "Thu xã",
+            (Chunshe, Korean) => "춘사",
+            (Qiushe, Korean) => "추사",
+            (Chunshe, Japanese) => "春社日",
+            (Qiushe, Japanese) => "秋社日",
+            (Chunshe, _) => "春社",
+            (Qiushe, _) => "秋社",
+        }
+    }
+}
+
+impl Translate for She {
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        self.static_translate(language).fmt(f)
+    }
+}
+
+/// The name of a nine, as "三九".
+fn translate_jiu(jiu: u8, language: Language, f: &mut Formatter) -> FmtResult {
+    let index = jiu as usize - 1;
+    match language {
+        Language::English => write!(
+            f,
+            "{}jiu",
+            ["Yi", "Er", "San", "Si", "Wu", "Liu", "Qi", "Ba", "Jiu"][index]
+        ),
+        Language::Vietnamese => write!(
+            f,
+            "{} cửu",
+            ["Nhất", "Nhị", "Tam", "Tứ", "Ngũ", "Lục", "Thất", "Bát", "Cửu"][index]
+        ),
+        Language::Korean => write!(
+            f,
+            "{}구",
+            get_char_as_str("일이삼사오육칠팔구", index).unwrap()
+        ),
+        _ => write!(f, "{}九", get_char_as_str(NUMBER, index).unwrap()),
+    }
+}
+
+impl Translate for Period {
+    /// The first day is named by the period alone, with Chufu as "入伏", and other days as
+    /// "三九第5天".
+    fn translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        match *self {
+            Period::Fu(Fu::Chufu, 1)
+                if matches!(
+                    language,
+                    Language::ChineseSimplified | Language::ChineseTraditional
+                ) =>
+            {
+                write!(f, "入伏")
+            }
+            Period::Fu(fu, _) => fu.translate(language, f),
+            Period::Jiu(jiu, _) => translate_jiu(jiu, language, f),
+        }?;
+        let day = self.day();
+        if day == 1 {
+            return Ok(());
+        }
+        match language {
+            Language::English => write!(f, " day {day}"),
+            Language::Vietnamese => write!(f, " ngày {day}"),
+            Language::Korean => write!(f, " {day}일째"),
+            Language::Japanese => write!(f, "第{day}日"),
+            _ => write!(f, "第{day}天"),
+        }
+    }
+}
+
+impl ShortTranslate for Period {
+    /// The name of the period, on its first day only.
+    fn short_translate(&self, language: Language, f: &mut Formatter) -> FmtResult {
+        if self.day() != 1 {
+            return Ok(());
+        }
+        let translation = self.translate_to_string(language);
+        match language {
+            Language::Vietnamese => abbreviate_vietnamese(&translation, f),
+            _ => translation.fmt(f),
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test() {
+    use crate::date;
+    use Language::*;
+
+    // Zhongfu lasts 20 days in 2024, and 10 days in 2025
+    let sanfu = Sanfu::new(2024).unwrap();
+    assert_eq!(
+        sanfu,
+        Sanfu {
+            chufu: date(2024, 7, 15),
+            zhongfu: date(2024, 7, 25),
+            mofu: date(2024, 8, 14),
+        }
+    );
+    assert_eq!(sanfu.end(), date(2024, 8, 24));
+    let sanfu = Sanfu::new(2025).unwrap();
+    assert_eq!(
+        (sanfu.chufu, sanfu.zhongfu, sanfu.mofu),
+        (date(2025, 7, 20), date(2025, 7, 30), date(2025, 8, 9))
+    );
+
+    let period = Period::from_date(&date(2024, 7, 15)).unwrap();
+    assert_eq!(period, Period::Fu(Fu::Chufu, 1));
+    assert_eq!(period.translate_to_string(ChineseSimplified), "入伏");
+    assert_eq!(period.short().translate_to_string(Korean), "초복");
+    let period = Period::from_date(&date(2024, 8, 13)).unwrap();
+    assert_eq!(period, Period::Fu(Fu::Zhongfu, 20));
+    assert_eq!(period.short().translate_to_string(English), "");
+    assert_eq!(Period::from_date(&date(2024, 8, 24)), None);
+
+    // Dongzhi of 2024 is on 12-21
+    let shujiu = Shujiu::new(2024).unwrap();
+    assert_eq!(shujiu.end(), date(2025, 3, 12));
+    let period = Period::from_date(&date(2025, 1, 12)).unwrap();
+    assert_eq!(period, Period::Jiu(3, 5));
+    assert_eq!(period.translate_to_string(ChineseSimplified), "三九第5天");
+    assert_eq!(period.translate_to_string(English), "Sanjiu day 5");
+    assert_eq!(
+        Period::from_date(&date(2024, 12, 21)).map(|period| period.translate_to_string(Vietnamese)),
+        Some("Nhất cửu".to_string())
+    );
+    assert_eq!(Period::from_date(&date(2024, 12, 20)), None);
+    assert_eq!(
+        Period::from_date(&date(2025, 3, 11)),
+        Some(Period::Jiu(9, 9))
+    );
+
+    // Mangzhong of 2024 is on 06-05, Xiazhi on 06-21, and Xiaoshu on 07-06, a wei day itself
+    let meiyu = Meiyu::new(2024, MeiyuRule::BingWei).unwrap();
+    assert_eq!(meiyu.rumei, date(2024, 6, 11));
+    assert_eq!(meiyu.chumei, Some(date(2024, 7, 18)));
+    let meiyu = Meiyu::new(2024, MeiyuRule::RenGeng).unwrap();
+    assert_eq!(meiyu.rumei, date(2024, 6, 7));
+    assert_eq!(meiyu.chumei, Some(date(2024, 6, 25)));
+    let meiyu = Meiyu::new(2024, MeiyuRule::Japanese).unwrap();
+    assert_eq!(meiyu.rumei, date(2024, 6, 10));
+    assert_eq!(meiyu.chumei, None);
+
+    // Lichun of 2024 is a wu day itself, and is not counted
+    assert_eq!(
+        She::Chunshe.date(2024, SheRule::FifthWu),
+        Some(date(2024, 3, 25))
+    );
+    assert_eq!(
+        She::Qiushe.date(2024, SheRule::FifthWu),
+        Some(date(2024, 9, 21))
+    );
+    // Chunfen of 2024 is on 03-20, 5 days after a wu day
+    assert_eq!(
+        She::Chunshe.date(2024, SheRule::NearestEquinox),
+        Some(date(2024, 3, 15))
+    );
+    assert_eq!(She::Qiushe.translate_to_string(ChineseTraditional), "秋社");
+}